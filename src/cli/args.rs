@@ -12,11 +12,24 @@ fn is_boolean_flag(flag: &str) -> bool {
         "dry-run"
             | "force"
             | "no-move"
+            | "no-template"
             | "help"
             | "clipboard"
             | "clipboard-only"
             | "setup"
             | "commit"
+            | "ref"
+            | "memo"
+            | "size"
+            | "title"
+            | "recursive"
+            | "watch"
+            | "undo"
+            | "check"
+            | "registry"
+            | "lazy"
+            | "lint"
+            | "strict"
     ) || flag == "copy"
 }
 
@@ -52,12 +65,12 @@ pub fn parse_args(args: &[String]) -> ParsedArgs {
         }
 
         let next = args.get(index + 1);
-        if let Some(next_value) = next {
-            if !next_value.starts_with("--") {
-                options.insert(key.to_string(), next_value.to_string());
-                index += 2;
-                continue;
-            }
+        if let Some(next_value) = next
+            && !next_value.starts_with("--")
+        {
+            options.insert(key.to_string(), next_value.to_string());
+            index += 2;
+            continue;
         }
 
         options.insert(key.to_string(), "true".to_string());
@@ -6,12 +6,27 @@ const COMMANDS: &[(&str, &str, &str)] = &[
     ),
     (
         "-svg2tsx",
-        "<directory> [--ext=.svg] [--dry-run] [--force] [--no-move]",
+        "<directory> [--ext=.svg] [--dry-run] [--force] [--no-move] [--colors=current|preserve|props|vars] [--optimize=all|none|LIST] [--precision=3] [--ids=use-id|prefix|keep] [--target=web,react-native,solid,vue,svelte] [--template=path] [--no-template] [--ref] [--memo] [--size[=24]] [--title] [--recursive] [--out-dir=path] [--watch] [--undo] [--check] [--case=pascal|camel] [--prefix=Icon] [--suffix=Icon] [--registry] [--lazy] [--lint] [--rules=LIST] [--dimensions=keep|remove|em]",
         "Convert SVG files in a directory to React components",
     ),
+    (
+        "-svg2sprite",
+        "<directory> [--out=sprite.svg] [--types=sprite.ts] [--ext=.svg] [--colors=current|preserve] [--optimize=all|none|LIST] [--precision=3] [--dry-run] [--check]",
+        "Merge SVG files in a directory into a <symbol> sprite sheet",
+    ),
+    (
+        "-svggallery",
+        "<directory> [--out=gallery.html] [--out-dir=path] [--import=path] [--ext=.svg] [--colors=current|preserve] [--optimize=all|none|LIST] [--precision=3] [--dry-run]",
+        "Render a static HTML gallery of the icons in a directory",
+    ),
+    (
+        "-svglint",
+        "<directory> [--ext=.svg] [--recursive] [--rules=name:off|warning|error,...] [--strict]",
+        "Check SVG files for problems before converting them",
+    ),
     (
         "-img2export",
-        "<directory> <output_file> [--ext=.svg,.png] [--dry-run]",
+        "<directory> <output_file> [--ext=.svg,.png] [--dry-run] [--check] [--case=pascal|camel] [--prefix=Icon] [--suffix=Icon]",
         "Export image files in a directory as named exports",
     ),
     (
@@ -21,7 +36,7 @@ const COMMANDS: &[(&str, &str, &str)] = &[
     ),
     (
         "-config",
-        "[--show] [--path] [--set-backend=gemini|opencode] [--unset-backend] [--set-key=VALUE] [--unset-key]",
+        "[--show] [--path] [--set-backend=gemini|opencode] [--unset-backend] [--set-key=VALUE] [--unset-key] [--set-template=PATH] [--unset-template]",
         "Inspect and update ~/.cozyutils/config.json",
     ),
 ];
@@ -31,7 +46,7 @@ pub fn usage() -> String {
     for (flag, args, _) in COMMANDS {
         text.push_str(&format!("  {} {}\n", flag, args));
     }
-    text.push_str("\n");
+    text.push('\n');
     text
 }
 
@@ -41,7 +56,7 @@ pub fn help() -> String {
         text.push_str(&format!("  {} {}  {}\n", flag, args, description));
     }
     text.push_str(
-    "\nFlags by command:\n  -svg2tsx\n    --ext=.svg          Override extensions to include\n    --dry-run           Print planned changes only\n    --force             Overwrite existing output files\n    --no-move           Keep original SVGs in place\n  -cmsg\n    --out=path           Output commit message to a file\n    --model=MODEL        Override model name\n    --backend=VALUE      Select backend: gemini or opencode\n    --clipboard          Copy commit message to clipboard\n    --clipboard-only     Only copy to clipboard (skip stdout/file)\n    --copy               Copy commit message to clipboard\n    --commit             Run git commit with generated message\n  -config\n    --show               Print config with secrets masked\n    --path               Print config file path\n    --set-backend=VALUE  Set default backend: gemini or opencode\n    --unset-backend      Remove default backend from config\n    --set-key=VALUE      Set Gemini API key in config\n    --unset-key          Remove Gemini API key from config\n  -img2export\n    --ext=.svg,.png     Override extensions to include\n    --dry-run           Print planned changes only\n  -prmsg\n    --base=origin/dev    Base ref for PR message generation\n    --out=path           Output PR message to a file\n    --model=MODEL        Override model name\n    --backend=VALUE      Select backend: gemini or opencode\n    --clipboard          Copy PR message to clipboard\n    --clipboard-only     Only copy to clipboard (skip stdout/file)\n    --copy               Copy PR message to clipboard\n    --setup              Create ~/.cozyutils/config.json\n    --key=VALUE          API key for --setup when using Gemini\n  Global\n    --help, -h          Show help\n    --version, -v       Show version\n\n",
+    "\nFlags by command:\n  -svg2tsx\n    --ext=.svg          Override extensions to include\n    --dry-run           Print planned changes as diffs without writing\n    --force             Rebuild every icon and overwrite existing output files\n    --no-move           Keep original SVGs in place\n    --colors=MODE       Color handling: current, preserve, props, or vars\n    --optimize=LIST     Optimization passes: all (default), none, or a comma list\n    --precision=3       Decimal places kept by the precision pass\n    --ids=MODE          Scope internal ids: use-id (default), prefix, or keep\n    --target=LIST       Output targets: web (default), react-native, solid, vue, svelte\n    --template=path     Custom component template for TSX targets\n    --no-template       Ignore the template set in the config for this run\n    --ref               Wrap React components in forwardRef\n    --memo              Wrap React components in React.memo\n    --size[=24]         Add a size prop that sets width and height\n    --title             Add title and titleId props for accessible icons\n    --recursive         Also convert SVGs in nested directories\n    --out-dir=path      Write components and barrels here; originals stay put\n    --watch             Keep running and regenerate components when SVGs change\n    --undo              Revert the last run using its journal\n    --check             Exit non-zero if generated files are out of date\n    --case=pascal       Component name casing: pascal (default) or camel\n    --prefix=Icon       Prepend a word to every component name\n    --suffix=Icon       Append a word to every component name\n    --registry          Write icons.ts with an IconName union and name-to-component map\n    --lazy              Also write icons.lazy.ts with React.lazy components (implies --registry)\n    --lint              Lint every SVG first and convert nothing if any has errors\n    --rules=LIST        Lint rule severities, e.g. hardcoded-size:error,script:off (implies --lint)\n    --dimensions=MODE   Root width/height: keep (default), remove, or em (1em)\n  -svg2sprite\n    --out=sprite.svg    Sprite file to write (default: <directory>/sprite.svg)\n    --types=sprite.ts   TypeScript file with the symbol name union\n    --ext=.svg          Override extensions to include\n    --colors=MODE       Color handling: current (default) or preserve\n    --optimize=LIST     Optimization passes: all (default), none, or a comma list\n    --precision=3       Decimal places kept by the precision pass\n    --dry-run           Print planned changes as diffs without writing\n    --check             Exit non-zero if the sprite is out of date\n  -svggallery\n    --out=gallery.html  HTML file to write (default: gallery.html in the output directory)\n    --out-dir=path      Where -svg2tsx wrote components and its manifest\n    --import=path       Module path used in the copyable import statements\n    --ext=.svg          Override extensions to include\n    --colors=MODE       Color handling for the converted preview: current (default) or preserve\n    --optimize=LIST     Optimization passes: all (default), none, or a comma list\n    --precision=3       Decimal places kept by the precision pass\n    --dry-run           Print planned changes as diffs without writing\n  -svglint\n    --ext=.svg          Override extensions to include\n    --recursive         Also lint SVGs in nested directories\n    --rules=LIST        Rule severities, e.g. non-square-view-box:error,raster-image:off\n    --strict            Exit non-zero on warnings as well as errors\n  -cmsg\n    --out=path           Output commit message to a file\n    --model=MODEL        Override model name\n    --backend=VALUE      Select backend: gemini or opencode\n    --clipboard          Copy commit message to clipboard\n    --clipboard-only     Only copy to clipboard (skip stdout/file)\n    --copy               Copy commit message to clipboard\n    --commit             Run git commit with generated message\n  -config\n    --show               Print config with secrets masked\n    --path               Print config file path\n    --set-backend=VALUE  Set default backend: gemini or opencode\n    --unset-backend      Remove default backend from config\n    --set-key=VALUE      Set Gemini API key in config\n    --unset-key          Remove Gemini API key from config\n    --set-template=PATH  Set default -svg2tsx component template\n    --unset-template     Remove default component template from config\n  -img2export\n    --ext=.svg,.png     Override extensions to include\n    --dry-run           Print planned changes as diffs without writing\n    --check             Exit non-zero if the barrel is out of date\n    --case=pascal       Export name casing: pascal (default) or camel\n    --prefix=Icon       Prepend a word to every export name\n    --suffix=Icon       Append a word to every export name\n  -prmsg\n    --base=origin/dev    Base ref for PR message generation\n    --out=path           Output PR message to a file\n    --model=MODEL        Override model name\n    --backend=VALUE      Select backend: gemini or opencode\n    --clipboard          Copy PR message to clipboard\n    --clipboard-only     Only copy to clipboard (skip stdout/file)\n    --copy               Copy PR message to clipboard\n    --setup              Create ~/.cozyutils/config.json\n    --key=VALUE          API key for --setup when using Gemini\n  Global\n    --help, -h          Show help\n    --version, -v       Show version\n\n",
   );
     text
 }
@@ -1,18 +1,28 @@
-use std::collections::HashMap;
 use std::path::Path;
 
 use crate::cli::args::parse_args;
-use crate::utils::fs::{make_component_name, read_dir_and_sort, write_string};
+use crate::utils::diff::unified_diff;
+use crate::utils::fs::{read_dir_and_sort, read_to_string, write_string};
+use crate::utils::journal::Journal;
+use crate::utils::naming::{NameOptions, unique_identifiers};
 
 pub fn any_to_export(extensions: Vec<&str>, args: Vec<String>) -> Result<(), String> {
     let parsed = parse_args(&args);
-    let directory = parsed.positional.get(0).cloned().unwrap_or_default();
+    let directory = parsed.positional.first().cloned().unwrap_or_default();
     let output_file = parsed.positional.get(1).cloned().unwrap_or_default();
-    let dry_run = parsed.options.get("dry-run").is_some();
+    let dry_run = parsed.options.contains_key("dry-run");
+    let check = parsed.options.contains_key("check");
     let custom_extensions = parsed.options.get("ext").cloned().unwrap_or_default();
+    let name_options = NameOptions::parse(
+        parsed.options.get("case"),
+        parsed.options.get("prefix"),
+        parsed.options.get("suffix"),
+    )?;
 
-    if parsed.options.get("help").is_some() {
-        println!("Usage: -img2export <directory> <output_file> [--ext=.svg,.png] [--dry-run]");
+    if parsed.options.contains_key("help") {
+        println!(
+            "Usage: -img2export <directory> <output_file> [--ext=.svg,.png] [--dry-run] [--check] [--case=pascal|camel] [--prefix=Icon] [--suffix=Icon]"
+        );
         return Ok(());
     }
 
@@ -37,38 +47,45 @@ pub fn any_to_export(extensions: Vec<&str>, args: Vec<String>) -> Result<(), Str
         return Ok(());
     }
 
-    let mut name_counts: HashMap<String, usize> = HashMap::new();
-    let export_names: Vec<String> = files
-        .iter()
-        .map(|file| {
-            let filename = file.split('.').next().unwrap_or("");
-            let base_name = make_component_name(filename);
-            let count = name_counts.get(&base_name).cloned().unwrap_or(0);
-            name_counts.insert(base_name.clone(), count + 1);
-            if count > 0 {
-                format!("{}{}", base_name, count + 1)
-            } else {
-                base_name
-            }
-        })
-        .collect();
-
-    let output = files
-        .iter()
-        .zip(export_names.iter())
-        .map(|(file, export_name)| {
-            format!(
-                "export {{ default as {} }} from \"./{}\";",
-                export_name, file
-            )
-        })
-        .collect::<Vec<_>>()
-        .join("\n")
-        + "\n";
+    let output = export_statements(&files, &name_options).join("\n") + "\n";
 
     let path = Path::new(&directory).join(&output_file);
+    if check {
+        let previous = read_to_string(&path).unwrap_or_default();
+        if previous == output {
+            println!(
+                "anyToExport - Check passed. {} is up to date.",
+                path.display()
+            );
+            return Ok(());
+        }
+        let label = path.display().to_string();
+        let old_label = if path.exists() {
+            label.as_str()
+        } else {
+            "/dev/null"
+        };
+        print!("{}", unified_diff(&previous, &output, old_label, &label));
+        return Err(format!(
+            "anyToExport - Check failed: {} is out of date.",
+            path.display()
+        ));
+    }
+
     if dry_run {
-        println!("anyToExport - Dry run. Would write {}", path.display());
+        let previous = read_to_string(&path).unwrap_or_default();
+        if previous == output {
+            println!("anyToExport - Dry run. {} is up to date.", path.display());
+            return Ok(());
+        }
+        let label = path.display().to_string();
+        let old_label = if path.exists() {
+            label.as_str()
+        } else {
+            "/dev/null"
+        };
+        println!("anyToExport - Dry run. Would write {}", label);
+        print!("{}", unified_diff(&previous, &output, old_label, &label));
         return Ok(());
     }
 
@@ -76,3 +93,48 @@ pub fn any_to_export(extensions: Vec<&str>, args: Vec<String>) -> Result<(), Str
     println!("anyToExport - Done! Wrote {}", path.display());
     Ok(())
 }
+
+fn export_statements(files: &[String], options: &NameOptions) -> Vec<String> {
+    let (names, warnings) = unique_identifiers(files, options);
+    for warning in warnings {
+        println!("anyToExport - Warning: {}", warning);
+    }
+    files
+        .iter()
+        .zip(names)
+        .map(|(file, name)| format!("export {{ default as {} }} from \"./{}\";", name, file))
+        .collect()
+}
+
+pub fn write_index(
+    directory: &Path,
+    extension: &str,
+    namespaces: &[(String, String)],
+    name_options: &NameOptions,
+    journal: &mut Journal,
+) -> Result<bool, String> {
+    let files = journal.list_files(directory, &[extension.to_string()]);
+    let mut statements = export_statements(&files, name_options);
+    statements.extend(
+        namespaces
+            .iter()
+            .map(|(name, path)| format!("export * as {} from \"{}\";", name, path)),
+    );
+    let path = directory.join("index.ts");
+    if statements.is_empty() {
+        if path.exists() {
+            journal.remove_file(&path)?;
+            if !journal.is_preview() {
+                println!("anyToExport - Removed {}", path.display());
+            }
+        }
+        return Ok(false);
+    }
+
+    journal.ensure_dir(directory)?;
+    journal.write_string(&path, &(statements.join("\n") + "\n"))?;
+    if !journal.is_preview() {
+        println!("anyToExport - Done! Wrote {}", path.display());
+    }
+    Ok(true)
+}
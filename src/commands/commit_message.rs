@@ -50,13 +50,13 @@ pub fn commit_message(args: Vec<String>) -> Result<(), String> {
             "opencode" => DEFAULT_OPENCODE_MODEL.to_string(),
             _ => DEFAULT_GEMINI_MODEL.to_string(),
         });
-    let clipboard_only = parsed.options.get("clipboard-only").is_some();
+    let clipboard_only = parsed.options.contains_key("clipboard-only");
     let clipboard = clipboard_only
-        || parsed.options.get("clipboard").is_some()
-        || parsed.options.get("copy").is_some();
-    let should_commit = parsed.options.get("commit").is_some();
+        || parsed.options.contains_key("clipboard")
+        || parsed.options.contains_key("copy");
+    let should_commit = parsed.options.contains_key("commit");
 
-    if parsed.options.get("help").is_some() {
+    if parsed.options.contains_key("help") {
         println!(
             "Usage: -cmsg [--out=path] [--model=MODEL] [--backend=gemini|opencode] [--clipboard] [--clipboard-only] [--commit]"
         );
@@ -113,10 +113,8 @@ pub fn commit_message(args: Vec<String>) -> Result<(), String> {
             std::path::Path::new(&output_path),
             &format!("{}\n", commit_message_text),
         )?;
-        if clipboard {
-            if let Err(error) = copy_to_clipboard(&commit_message_text) {
-                println!("{}", error);
-            }
+        if clipboard && let Err(error) = copy_to_clipboard(&commit_message_text) {
+            println!("{}", error);
         }
         return Ok(());
     }
@@ -125,10 +123,8 @@ pub fn commit_message(args: Vec<String>) -> Result<(), String> {
         println!("{}", commit_message_text);
     }
 
-    if clipboard {
-        if let Err(error) = copy_to_clipboard(&commit_message_text) {
-            println!("{}", error);
-        }
+    if clipboard && let Err(error) = copy_to_clipboard(&commit_message_text) {
+        println!("{}", error);
     }
 
     Ok(())
@@ -25,7 +25,7 @@ pub fn config_command(args: Vec<String>) -> Result<(), String> {
 
     if parsed.options.contains_key("help") {
         println!(
-            "Usage: -config [--show] [--path] [--set-backend=gemini|opencode] [--unset-backend] [--set-key=VALUE] [--unset-key]"
+            "Usage: -config [--show] [--path] [--set-backend=gemini|opencode] [--unset-backend] [--set-key=VALUE] [--unset-key] [--set-template=PATH] [--unset-template]"
         );
         return Ok(());
     }
@@ -56,6 +56,20 @@ pub fn config_command(args: Vec<String>) -> Result<(), String> {
         return Err("Use either --set-backend or --unset-backend, not both.".to_string());
     }
 
+    let set_template = match parsed.options.get("set-template") {
+        Some(value) => Some(
+            std::path::absolute(value)
+                .map_err(|error| format!("Failed to resolve template path {}: {}", value, error))?
+                .to_string_lossy()
+                .to_string(),
+        ),
+        None => None,
+    };
+    let unset_template = parsed.options.contains_key("unset-template");
+    if set_template.is_some() && unset_template {
+        return Err("Use either --set-template or --unset-template, not both.".to_string());
+    }
+
     let key_update = if let Some(value) = set_key {
         Some(Some(value))
     } else if unset_key {
@@ -72,14 +86,22 @@ pub fn config_command(args: Vec<String>) -> Result<(), String> {
         None
     };
 
-    if key_update.is_none() && backend_update.is_none() {
+    let template_update = if let Some(value) = set_template.as_deref() {
+        Some(Some(value))
+    } else if unset_template {
+        Some(None)
+    } else {
+        None
+    };
+
+    if key_update.is_none() && backend_update.is_none() && template_update.is_none() {
         return Err(
-            "No config action specified. Use --show, --path, --set-backend, --unset-backend, --set-key, or --unset-key."
+            "No config action specified. Use --show, --path, --set-backend, --unset-backend, --set-key, --unset-key, --set-template, or --unset-template."
                 .to_string(),
         );
     }
 
-    let path = update_config(key_update, backend_update)?;
+    let path = update_config(key_update, backend_update, template_update)?;
     println!("config - Updated {}", path.display());
     Ok(())
 }
@@ -2,4 +2,7 @@ pub mod any_to_export;
 pub mod commit_message;
 pub mod config;
 pub mod pr_message;
+pub mod svg_gallery;
+pub mod svg_lint;
+pub mod svg_to_sprite;
 pub mod svg_to_tsx;
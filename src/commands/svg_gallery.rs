@@ -0,0 +1,366 @@
+use std::path::{Path, PathBuf};
+
+use crate::cli::args::parse_args;
+use crate::commands::svg_to_sprite::load_icon;
+use crate::utils::colors::ColorMode;
+use crate::utils::dimensions::{DimensionMode, normalize_dimensions};
+use crate::utils::fs::{list_subdirectories, read_dir_and_sort, read_to_string};
+use crate::utils::ids::{collect_ids, prefix_classes, prefix_ids};
+use crate::utils::journal::Journal;
+use crate::utils::manifest::{Manifest, manifest_key};
+use crate::utils::naming::{file_stem, make_component_name, unique_identifiers};
+use crate::utils::optimize::OptimizeOptions;
+use crate::utils::svg::{
+    Element, Node, escape_xml, is_event_handler, is_javascript_link, is_script_element, parse_svg,
+    write_element,
+};
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+const GALLERY_STYLE: &str = r#"    :root { --icon-color: #1f2937; font-family: system-ui, sans-serif; color: #111827; }
+    body { margin: 0; background: #f9fafb; }
+    header { display: flex; gap: 16px; align-items: center; justify-content: space-between; padding: 16px 24px; background: #fff; border-bottom: 1px solid #e5e7eb; position: sticky; top: 0; }
+    h1 { font-size: 18px; margin: 0; }
+    .controls { display: flex; gap: 12px; align-items: center; font-size: 14px; }
+    .controls input[type="search"] { padding: 6px 8px; border: 1px solid #d1d5db; border-radius: 6px; }
+    main { display: grid; grid-template-columns: repeat(auto-fill, minmax(220px, 1fr)); gap: 16px; padding: 24px; }
+    figure { margin: 0; padding: 16px; background: #fff; border: 1px solid #e5e7eb; border-radius: 8px; display: flex; flex-direction: column; gap: 12px; }
+    .previews { display: flex; gap: 8px; }
+    .preview { flex: 1; display: flex; flex-direction: column; align-items: center; gap: 6px; padding: 12px 0; border-radius: 6px; background: #f3f4f6; font-size: 11px; color: #6b7280; }
+    .preview svg, .preview img { width: 48px; height: 48px; }
+    .converted { color: var(--icon-color); }
+    figcaption { display: flex; flex-direction: column; gap: 6px; font-size: 13px; min-width: 0; }
+    figcaption span { color: #6b7280; overflow-wrap: anywhere; }
+    code { font-size: 11px; background: #f3f4f6; padding: 4px 6px; border-radius: 4px; overflow-wrap: anywhere; }
+    button { align-self: flex-start; font: inherit; font-size: 12px; padding: 4px 10px; border: 1px solid #d1d5db; border-radius: 6px; background: #fff; cursor: pointer; }
+    .missing { padding: 12px 0; }
+"#;
+
+const GALLERY_SCRIPT: &str = r#"    document.getElementById("color").addEventListener("input", (event) => {
+      document.documentElement.style.setProperty("--icon-color", event.target.value);
+    });
+    document.getElementById("filter").addEventListener("input", (event) => {
+      const query = event.target.value.toLowerCase();
+      for (const figure of document.querySelectorAll("figure")) {
+        figure.hidden = !figure.dataset.search.includes(query);
+      }
+    });
+    for (const button of document.querySelectorAll("button[data-copy]")) {
+      button.addEventListener("click", async () => {
+        await navigator.clipboard.writeText(button.dataset.copy);
+        button.textContent = "Copied!";
+        setTimeout(() => (button.textContent = "Copy import"), 1500);
+      });
+    }
+"#;
+
+struct GalleryIcon {
+    key: String,
+    source: PathBuf,
+    component_name: String,
+}
+
+pub fn svg_gallery(args: Vec<String>) -> Result<(), String> {
+    let parsed = parse_args(&args);
+    let directory = parsed.positional.first().cloned().unwrap_or_default();
+    let dry_run = parsed.options.contains_key("dry-run");
+    let out_dir = parsed.options.get("out-dir").cloned();
+    let custom_extensions = parsed.options.get("ext").cloned().unwrap_or_default();
+    let optimize_options = OptimizeOptions::parse(
+        parsed.options.get("optimize").map(|value| value.as_str()),
+        parsed.options.get("precision").map(|value| value.as_str()),
+    )?;
+    let color_mode = match parsed.options.get("colors") {
+        Some(value) => ColorMode::parse(value)?,
+        None => ColorMode::Current,
+    };
+    if matches!(color_mode, ColorMode::Props | ColorMode::Vars) {
+        return Err("The gallery only supports --colors=current or --colors=preserve.".to_string());
+    }
+
+    if parsed.options.contains_key("help") {
+        println!(
+            "Usage: -svggallery <directory> [--out=gallery.html] [--out-dir=path] [--import=path] [--ext=.svg] [--colors=current|preserve] [--optimize=all|none|LIST] [--precision=3] [--dry-run]"
+        );
+        return Ok(());
+    }
+
+    if directory.is_empty() {
+        println!("Missing required argument. Expected: <directory>");
+        return Ok(());
+    }
+
+    let ext_list: Vec<String> = if !custom_extensions.is_empty() {
+        custom_extensions
+            .split(',')
+            .map(|ext| ext.trim().to_string())
+            .collect()
+    } else {
+        vec![".svg".to_string()]
+    };
+
+    let directory = Path::new(&directory);
+    let output = out_dir.as_deref().map(Path::new).unwrap_or(directory);
+    let import_path = parsed
+        .options
+        .get("import")
+        .cloned()
+        .unwrap_or_else(|| output.to_string_lossy().trim_end_matches('/').to_string());
+    let out = parsed
+        .options
+        .get("out")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| output.join("gallery.html"));
+
+    let icons = gallery_icons(directory, output, &ext_list)?;
+    if icons.is_empty() {
+        println!("No matching files found in {}", directory.display());
+        return Ok(());
+    }
+
+    let mut figures = Vec::new();
+    for (index, icon) in icons.iter().enumerate() {
+        figures.push(render_figure(
+            index,
+            icon,
+            &import_path,
+            &optimize_options,
+            color_mode,
+        )?);
+    }
+    let title = format!("Icons in {}", directory.display());
+    let html = format!(
+        "<!doctype html>\n<html lang=\"en\">\n<head>\n  <meta charset=\"utf-8\">\n  <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n  <title>{title}</title>\n  <style>\n{GALLERY_STYLE}  </style>\n</head>\n<body>\n  <header>\n    <h1>{title} ({count})</h1>\n    <div class=\"controls\">\n      <input type=\"search\" id=\"filter\" placeholder=\"Filter icons\">\n      <label>Color <input type=\"color\" id=\"color\" value=\"#1f2937\"></label>\n    </div>\n  </header>\n  <main>\n{figures}\n  </main>\n  <script>\n{GALLERY_SCRIPT}  </script>\n</body>\n</html>\n",
+        title = escape_xml(&title, false),
+        count = icons.len(),
+        figures = figures.join("\n"),
+    );
+
+    let mut journal = if dry_run {
+        Journal::preview()
+    } else {
+        Journal::default()
+    };
+    if let Some(parent) = out.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        journal.ensure_dir(parent)?;
+    }
+    journal.write_string(&out, &html)?;
+    journal.commit()?;
+    if !dry_run {
+        println!(
+            "svgGallery - Done! Wrote {} icon(s) to {}",
+            icons.len(),
+            out.display()
+        );
+    }
+    Ok(())
+}
+
+fn gallery_icons(
+    directory: &Path,
+    output: &Path,
+    ext_list: &[String],
+) -> Result<Vec<GalleryIcon>, String> {
+    let manifest = Manifest::load(output)?;
+    if !manifest.icons.is_empty() {
+        return Ok(manifest
+            .icons
+            .iter()
+            .filter_map(|(key, entry)| {
+                let component_name = entry
+                    .outputs
+                    .keys()
+                    .next()
+                    .map(|output_key| file_stem(output_key.rsplit('/').next().unwrap_or("")))?
+                    .to_string();
+                let source = source_path(directory, key)?;
+                Some(GalleryIcon {
+                    key: key.clone(),
+                    source,
+                    component_name,
+                })
+            })
+            .collect());
+    }
+
+    let mut icons = Vec::new();
+    collect_icons(directory, directory, ext_list, &mut icons);
+    Ok(icons)
+}
+
+fn collect_icons(root: &Path, directory: &Path, ext_list: &[String], icons: &mut Vec<GalleryIcon>) {
+    let mut files = read_dir_and_sort(&directory.to_string_lossy(), ext_list);
+    let originals = directory.join("original");
+    if originals.is_dir() {
+        files.extend(read_dir_and_sort(&originals.to_string_lossy(), ext_list));
+        files.sort();
+        files.dedup();
+    }
+    let (names, warnings) = unique_identifiers(&files, &Default::default());
+    for warning in warnings {
+        println!("svgGallery - Warning: {}", warning);
+    }
+    for (file, component_name) in files.into_iter().zip(names) {
+        let key = manifest_key(root, &directory.join(&file));
+        if let Some(source) = source_path(root, &key) {
+            icons.push(GalleryIcon {
+                key,
+                source,
+                component_name,
+            });
+        }
+    }
+
+    for subdirectory in list_subdirectories(directory) {
+        if subdirectory != "original" {
+            collect_icons(root, &directory.join(subdirectory), ext_list, icons);
+        }
+    }
+}
+
+fn source_path(directory: &Path, key: &str) -> Option<PathBuf> {
+    let path = directory.join(key);
+    if path.exists() {
+        return Some(path);
+    }
+    let original = path.parent()?.join("original").join(path.file_name()?);
+    original.exists().then_some(original)
+}
+
+fn render_figure(
+    index: usize,
+    icon: &GalleryIcon,
+    import_path: &str,
+    optimize_options: &OptimizeOptions,
+    color_mode: ColorMode,
+) -> Result<String, String> {
+    let original = read_to_string(&icon.source)?;
+    let converted = load_icon(&icon.source, optimize_options, color_mode).ok();
+
+    let (namespace, module) = match icon.key.rsplit_once('/') {
+        Some((folder, _)) => (
+            folder
+                .split('/')
+                .map(make_component_name)
+                .collect::<Vec<_>>()
+                .join("."),
+            format!("{}/{}", import_path, folder),
+        ),
+        None => (String::new(), import_path.to_string()),
+    };
+    let statement = format!("import {{ {} }} from \"{}\";", icon.component_name, module);
+    let display_name = if namespace.is_empty() {
+        icon.component_name.clone()
+    } else {
+        format!("{}.{}", namespace, icon.component_name)
+    };
+
+    Ok(format!(
+        "    <figure data-search=\"{search}\">\n      <div class=\"previews\">\n{original}\n{converted}\n      </div>\n      <figcaption>\n        <strong>{name}</strong>\n        <span>{key}</span>\n        <code>{statement}</code>\n        <button type=\"button\" data-copy=\"{copy}\">Copy import</button>\n      </figcaption>\n    </figure>",
+        search = escape_xml(
+            &format!("{} {}", display_name, icon.key).to_lowercase(),
+            true
+        ),
+        original = render_original(&original),
+        converted = render_converted(converted, &format!("converted-{}", index)),
+        name = escape_xml(&display_name, false),
+        key = escape_xml(&icon.key, false),
+        statement = escape_xml(&statement, false),
+        copy = escape_xml(&statement, true),
+    ))
+}
+
+fn render_missing(class: &str, label: &str) -> String {
+    format!(
+        "        <div class=\"preview {}\"><div class=\"missing\">Invalid SVG</div>{}</div>",
+        class, label
+    )
+}
+
+fn render_original(content: &str) -> String {
+    let Some(mut root) = parse_svg(content)
+        .ok()
+        .and_then(|mut document| document.root_mut().map(std::mem::take))
+    else {
+        return render_missing("original", "Original");
+    };
+    if !root.attributes.iter().any(|(name, _)| name == "xmlns") {
+        root.attributes
+            .insert(0, ("xmlns".to_string(), SVG_NAMESPACE.to_string()));
+    }
+    format!(
+        "        <div class=\"preview original\">\n          <img alt=\"\" src=\"data:image/svg+xml,{}\">\n          Original\n        </div>",
+        escape_xml(&percent_encode(&write_element(&root, 0)), true)
+    )
+}
+
+fn render_converted(root: Option<Element>, prefix: &str) -> String {
+    let Some(mut root) = root else {
+        return render_missing("converted", "Converted");
+    };
+    strip_scripts(&mut root);
+    let ids = collect_ids(&root);
+    prefix_ids(&mut root, &ids, prefix);
+    prefix_classes(&mut root, prefix);
+    normalize_dimensions(&mut root, DimensionMode::Remove);
+    format!(
+        "        <div class=\"preview converted\">\n{}\n          Converted\n        </div>",
+        write_element(&root, 5)
+    )
+}
+
+fn strip_scripts(element: &mut Element) {
+    element
+        .children
+        .retain(|child| !matches!(child, Node::Element(child) if is_script_element(child)));
+    element
+        .attributes
+        .retain(|(name, value)| !is_event_handler(name) && !is_javascript_link(name, value));
+    for child in element.children.iter_mut() {
+        if let Node::Element(child) = child {
+            strip_scripts(child);
+        }
+    }
+}
+
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'_'
+            | b'.'
+            | b'~'
+            | b'/'
+            | b':'
+            | b'='
+            | b','
+            | b';'
+            | b'('
+            | b')' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_scripts_regardless_of_case() {
+        let mut document = parse_svg(
+            "<svg ONLOAD=\"alert(1)\" onClick=\"alert(2)\"><SCRIPT>alert(3)</SCRIPT><Script>alert(4)</Script><a XLINK:HREF=\" JavaScript:alert(5)\"><path d=\"M0 0\" OnMouseOver=\"alert(6)\"/></a></svg>",
+        )
+        .unwrap();
+        let root = document.root_mut().unwrap();
+        strip_scripts(root);
+        assert_eq!(
+            write_element(root, 0),
+            "<svg>\n  <a>\n    <path d=\"M0 0\" />\n  </a>\n</svg>"
+        );
+    }
+}
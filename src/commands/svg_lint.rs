@@ -0,0 +1,84 @@
+use std::path::{Path, PathBuf};
+
+use crate::cli::args::parse_args;
+use crate::utils::fs::{list_subdirectories, read_dir_and_sort, read_to_string};
+use crate::utils::lint::{LintRules, Severity, count_severity, lint_svg, print_diagnostics};
+
+pub fn svg_lint(args: Vec<String>) -> Result<(), String> {
+    let parsed = parse_args(&args);
+    let directory = parsed.positional.first().cloned().unwrap_or_default();
+    let recursive = parsed.options.contains_key("recursive");
+    let strict = parsed.options.contains_key("strict");
+    let custom_extensions = parsed.options.get("ext").cloned().unwrap_or_default();
+    let rules = LintRules::parse(parsed.options.get("rules").map(|value| value.as_str()))?;
+
+    if parsed.options.contains_key("help") {
+        println!(
+            "Usage: -svglint <directory> [--ext=.svg] [--recursive] [--rules=name:off|warning|error,...] [--strict]"
+        );
+        return Ok(());
+    }
+
+    if directory.is_empty() {
+        println!("Missing required argument. Expected: <directory>");
+        return Ok(());
+    }
+
+    let ext_list: Vec<String> = if !custom_extensions.is_empty() {
+        custom_extensions
+            .split(',')
+            .map(|ext| ext.trim().to_string())
+            .collect()
+    } else {
+        vec![".svg".to_string()]
+    };
+
+    let mut files = Vec::new();
+    collect_files(Path::new(&directory), &ext_list, recursive, &mut files);
+    if files.is_empty() {
+        println!("No matching files found in {}", directory);
+        return Ok(());
+    }
+
+    let (errors, warnings) = lint_files(&files, &rules)?;
+    let summary = format!(
+        "{} error(s), {} warning(s) in {} file(s)",
+        errors,
+        warnings,
+        files.len()
+    );
+    if errors > 0 || (strict && warnings > 0) {
+        return Err(format!("svgLint - Failed: {}.", summary));
+    }
+    println!("svgLint - Done! {}.", summary);
+    Ok(())
+}
+
+fn collect_files(directory: &Path, ext_list: &[String], recursive: bool, files: &mut Vec<PathBuf>) {
+    files.extend(
+        read_dir_and_sort(&directory.to_string_lossy(), ext_list)
+            .into_iter()
+            .map(|filename| directory.join(filename)),
+    );
+    if !recursive {
+        return;
+    }
+    for subdirectory in list_subdirectories(directory) {
+        if subdirectory != "original" {
+            collect_files(&directory.join(subdirectory), ext_list, recursive, files);
+        }
+    }
+}
+
+pub fn lint_files(files: &[PathBuf], rules: &LintRules) -> Result<(usize, usize), String> {
+    let mut errors = 0;
+    let mut warnings = 0;
+    for path in files {
+        let content = read_to_string(path)?;
+        let diagnostics = lint_svg(&content, rules);
+        print_diagnostics(&path.display().to_string(), &diagnostics);
+        errors += count_severity(&diagnostics, Severity::Error);
+        warnings += count_severity(&diagnostics, Severity::Warning);
+    }
+    Ok((errors, warnings))
+}
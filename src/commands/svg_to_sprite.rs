@@ -0,0 +1,243 @@
+use std::path::{Path, PathBuf};
+
+use crate::cli::args::parse_args;
+use crate::utils::colors::{ColorMode, apply_current_color};
+use crate::utils::dimensions::view_box;
+use crate::utils::fs::{read_dir_and_sort, read_to_string};
+use crate::utils::ids::{collect_ids, prefix_classes, prefix_ids};
+use crate::utils::journal::Journal;
+use crate::utils::naming::unique_symbol_ids;
+use crate::utils::optimize::{OptimizeOptions, optimize};
+use crate::utils::svg::{Element, Node, parse_svg, write_element};
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+const XLINK_NAMESPACE: &str = "http://www.w3.org/1999/xlink";
+const TYPE_NAME: &str = "SpriteIconName";
+const DROPPED_ROOT_ATTRIBUTES: &[&str] = &[
+    "xmlns",
+    "xmlns:xlink",
+    "version",
+    "width",
+    "height",
+    "x",
+    "y",
+    "id",
+    "viewBox",
+    "enable-background",
+    "xml:space",
+];
+
+pub fn svg_to_sprite(args: Vec<String>) -> Result<(), String> {
+    let parsed = parse_args(&args);
+    let directory = parsed.positional.first().cloned().unwrap_or_default();
+    let dry_run = parsed.options.contains_key("dry-run");
+    let check = parsed.options.contains_key("check");
+    let custom_extensions = parsed.options.get("ext").cloned().unwrap_or_default();
+    let optimize_options = OptimizeOptions::parse(
+        parsed.options.get("optimize").map(|value| value.as_str()),
+        parsed.options.get("precision").map(|value| value.as_str()),
+    )?;
+    let color_mode = match parsed.options.get("colors") {
+        Some(value) => ColorMode::parse(value)?,
+        None => ColorMode::Current,
+    };
+    if matches!(color_mode, ColorMode::Props | ColorMode::Vars) {
+        return Err("Sprites only support --colors=current or --colors=preserve.".to_string());
+    }
+
+    if parsed.options.contains_key("help") {
+        println!(
+            "Usage: -svg2sprite <directory> [--out=sprite.svg] [--types=sprite.ts] [--ext=.svg] [--colors=current|preserve] [--optimize=all|none|LIST] [--precision=3] [--dry-run] [--check]"
+        );
+        return Ok(());
+    }
+
+    if directory.is_empty() {
+        println!("Missing required argument. Expected: <directory>");
+        return Ok(());
+    }
+
+    let ext_list: Vec<String> = if !custom_extensions.is_empty() {
+        custom_extensions
+            .split(',')
+            .map(|ext| ext.trim().to_string())
+            .collect()
+    } else {
+        vec![".svg".to_string()]
+    };
+
+    let directory = Path::new(&directory);
+    let out = parsed
+        .options
+        .get("out")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| directory.join("sprite.svg"));
+    let types = parsed
+        .options
+        .get("types")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| out.with_extension("ts"));
+    let out_absolute = std::path::absolute(&out).unwrap_or_else(|_| out.clone());
+
+    let mut files = read_dir_and_sort(&directory.to_string_lossy(), &ext_list)
+        .into_iter()
+        .filter(|filename| {
+            std::path::absolute(directory.join(filename)).ok().as_ref() != Some(&out_absolute)
+        })
+        .collect::<Vec<_>>();
+    let originals = directory.join("original");
+    if originals.is_dir() {
+        files.extend(read_dir_and_sort(&originals.to_string_lossy(), &ext_list));
+        files.sort();
+        files.dedup();
+    }
+    if files.is_empty() {
+        println!("No matching files found in {}", directory.display());
+        return Ok(());
+    }
+
+    let (symbol_ids, warnings) = unique_symbol_ids(&files);
+    for warning in warnings {
+        println!("svgToSprite - Warning: {}", warning);
+    }
+
+    let mut sprite = Element::new("svg");
+    sprite
+        .attributes
+        .push(("xmlns".to_string(), SVG_NAMESPACE.to_string()));
+    for (filename, symbol_id) in files.iter().zip(&symbol_ids) {
+        let mut path = directory.join(filename);
+        if !path.exists() {
+            path = originals.join(filename);
+        }
+        let symbol = build_symbol(&path, symbol_id, &optimize_options, color_mode)?;
+        sprite.children.push(Node::Element(symbol));
+    }
+    let mut uses_xlink = false;
+    sprite.visit(&mut |element| {
+        uses_xlink |= element
+            .attributes
+            .iter()
+            .any(|(name, _)| name.starts_with("xlink:"));
+    });
+    if uses_xlink {
+        sprite
+            .attributes
+            .push(("xmlns:xlink".to_string(), XLINK_NAMESPACE.to_string()));
+    }
+    sprite
+        .attributes
+        .push(("style".to_string(), "display: none".to_string()));
+
+    let union = symbol_ids
+        .iter()
+        .map(|symbol_id| format!("  | \"{}\"", symbol_id))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let type_content = format!("export type {} =\n{};\n", TYPE_NAME, union);
+
+    let mut journal = if dry_run || check {
+        Journal::preview()
+    } else {
+        Journal::default()
+    };
+    let result = write_sprite(&out, &(write_element(&sprite, 0) + "\n"), &mut journal)
+        .and_then(|_| write_sprite(&types, &type_content, &mut journal))
+        .and_then(|_| journal.commit());
+    if let Err(error) = result {
+        journal.rollback()?;
+        return Err(error);
+    }
+
+    if check {
+        if journal.planned_changes() > 0 {
+            return Err(format!(
+                "svgToSprite - Check failed: {} is out of date.",
+                out.display()
+            ));
+        }
+        println!(
+            "svgToSprite - Check passed. {} symbol(s) are up to date.",
+            files.len()
+        );
+        return Ok(());
+    }
+    if dry_run {
+        return Ok(());
+    }
+
+    println!(
+        "svgToSprite - Done! Wrote {} symbol(s) to {} and {}",
+        files.len(),
+        out.display(),
+        types.display()
+    );
+    Ok(())
+}
+
+fn write_sprite(path: &Path, content: &str, journal: &mut Journal) -> Result<(), String> {
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        journal.ensure_dir(parent)?;
+    }
+    journal.write_string(path, content)
+}
+
+pub fn load_icon(
+    path: &Path,
+    optimize_options: &OptimizeOptions,
+    color_mode: ColorMode,
+) -> Result<Element, String> {
+    let content = read_to_string(path)?;
+    let mut document =
+        parse_svg(&content).map_err(|error| format!("{}: {}", path.display(), error))?;
+    optimize(&mut document, optimize_options);
+    let Some(root) = document.root_mut() else {
+        return Err(format!("{}: No <svg> element found", path.display()));
+    };
+
+    let file_color_mode = match root.remove_attribute("data-cozy-colors") {
+        Some(value) => {
+            ColorMode::parse(&value).map_err(|error| format!("{}: {}", path.display(), error))?
+        }
+        None => color_mode,
+    };
+    if file_color_mode == ColorMode::Current {
+        root.visit_mut(&mut apply_current_color);
+    }
+    Ok(std::mem::take(root))
+}
+
+fn build_symbol(
+    path: &Path,
+    symbol_id: &str,
+    optimize_options: &OptimizeOptions,
+    color_mode: ColorMode,
+) -> Result<Element, String> {
+    let mut root = load_icon(path, optimize_options, color_mode)?;
+    let prefix = format!("{}-", symbol_id);
+    let ids = collect_ids(&root);
+    prefix_ids(&mut root, &ids, &prefix);
+    prefix_classes(&mut root, &prefix);
+
+    let mut symbol = Element::new("symbol");
+    symbol
+        .attributes
+        .push(("id".to_string(), symbol_id.to_string()));
+    match view_box(&root) {
+        Some(view_box) => symbol.attributes.push(("viewBox".to_string(), view_box)),
+        None => println!(
+            "svgToSprite - Warning: {} has no viewBox or numeric width/height.",
+            path.display()
+        ),
+    }
+    symbol.attributes.extend(
+        root.attributes
+            .iter()
+            .filter(|(name, _)| !DROPPED_ROOT_ATTRIBUTES.contains(&name.as_str()))
+            .cloned(),
+    );
+    symbol.children = std::mem::take(&mut root.children);
+    Ok(symbol)
+}
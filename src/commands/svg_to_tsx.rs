@@ -1,24 +1,156 @@
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::{Duration, SystemTime};
 
-use regex::Regex;
+use notify::{Event, RecursiveMode, Watcher};
 
 use crate::cli::args::parse_args;
-use crate::commands::any_to_export::any_to_export;
+use crate::commands::any_to_export::write_index;
+use crate::commands::svg_lint::lint_files;
+use crate::utils::colors::{
+    ColorMode, apply_current_color, bind_colors, collect_palette, color_prop_name,
+};
+use crate::utils::config::load_svg_template_path;
+use crate::utils::dimensions::{DimensionMode, normalize_dimensions};
 use crate::utils::fs::{
-    component_template, ensure_dir, join_path, make_component_name, move_file, read_dir_and_sort,
-    read_to_string, write_string,
+    Component, ComponentProp, list_subdirectories, matches_extension, read_dir_and_sort,
+    read_to_string, remove_file, validate_template,
+};
+use crate::utils::ids::{IdMode, collect_ids, scope_ids, styles_reference_ids};
+use crate::utils::journal::{JOURNAL_FILENAME, Journal};
+use crate::utils::jsx::{element_to_jsx, json_string};
+use crate::utils::lint::LintRules;
+use crate::utils::manifest::{Manifest, ManifestEntry, content_hash, file_hash, manifest_key};
+use crate::utils::naming::{
+    NameOptions, file_stem, kebab_case, make_component_name, unique_identifiers, unique_kebab_names,
+};
+use crate::utils::optimize::{OptimizeOptions, optimize};
+use crate::utils::react::ReactOptions;
+use crate::utils::registry::{
+    LAZY_REGISTRY_FILENAME, REGISTRY_FILENAME, RegistryEntry, lazy_registry_content,
+    registry_content,
 };
+use crate::utils::svg::parse_svg;
+use crate::utils::targets::Target;
+
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(100);
 
 pub fn svg_to_tsx(args: Vec<String>) -> Result<(), String> {
     let parsed = parse_args(&args);
-    let directory = parsed.positional.get(0).cloned().unwrap_or_default();
-    let dry_run = parsed.options.get("dry-run").is_some();
-    let force = parsed.options.get("force").is_some();
-    let no_move = parsed.options.get("no-move").is_some();
+    let directory = parsed.positional.first().cloned().unwrap_or_default();
+    let dry_run = parsed.options.contains_key("dry-run");
+    let force = parsed.options.contains_key("force");
+    let no_move = parsed.options.contains_key("no-move");
+    let out_dir = parsed.options.get("out-dir").cloned();
+    let recursive = parsed.options.contains_key("recursive");
+    let watch = parsed.options.contains_key("watch");
+    let check = parsed.options.contains_key("check");
+    let lazy = parsed.options.contains_key("lazy");
+    let registry = lazy || parsed.options.contains_key("registry");
+    let lint_rules = if parsed.options.contains_key("lint") || parsed.options.contains_key("rules")
+    {
+        Some(LintRules::parse(
+            parsed.options.get("rules").map(|value| value.as_str()),
+        )?)
+    } else {
+        None
+    };
     let custom_extensions = parsed.options.get("ext").cloned().unwrap_or_default();
+    let optimize_options = OptimizeOptions::parse(
+        parsed.options.get("optimize").map(|value| value.as_str()),
+        parsed.options.get("precision").map(|value| value.as_str()),
+    )?;
+    let id_mode = match parsed.options.get("ids") {
+        Some(value) => IdMode::parse(value)?,
+        None => IdMode::UseId,
+    };
+    let color_mode = match parsed.options.get("colors") {
+        Some(value) => ColorMode::parse(value)?,
+        None => ColorMode::Current,
+    };
+    let dimension_mode = match parsed.options.get("dimensions") {
+        Some(value) => DimensionMode::parse(value)?,
+        None => DimensionMode::Keep,
+    };
+    let targets = Target::parse_list(
+        parsed
+            .options
+            .get("target")
+            .map(|value| value.as_str())
+            .unwrap_or("web"),
+    )?;
+    let react_options = ReactOptions {
+        forward_ref: parsed.options.contains_key("ref"),
+        memo: parsed.options.contains_key("memo"),
+        size: parsed.options.contains_key("size"),
+        default_size: match parsed.options.get("size") {
+            Some(value) => ReactOptions::parse_size(value)?,
+            None => None,
+        },
+        title: parsed.options.contains_key("title"),
+    };
+    let name_options = NameOptions::parse(
+        parsed.options.get("case"),
+        parsed.options.get("prefix"),
+        parsed.options.get("suffix"),
+    )?;
+    if !react_options.is_empty()
+        && let Some(target) = targets.iter().find(|target| !target.is_react())
+    {
+        return Err(format!(
+            "--ref, --memo, --size and --title are not supported for the {} target.",
+            target.name()
+        ));
+    }
+    if dimension_mode == DimensionMode::Em && targets.contains(&Target::ReactNative) {
+        return Err("--dimensions=em is not supported for the react-native target.".to_string());
+    }
+    if lazy && let Some(target) = targets.iter().find(|target| !target.is_react()) {
+        return Err(format!(
+            "--lazy is not supported for the {} target.",
+            target.name()
+        ));
+    }
+    let explicit_template = parsed.options.get("template").cloned();
+    if explicit_template.is_some()
+        && let Some(target) = targets.iter().find(|target| !target.supports_templates())
+    {
+        return Err(format!(
+            "Component templates are not supported for the {} target.",
+            target.name()
+        ));
+    }
+    let template_path = if parsed.options.contains_key("no-template") {
+        None
+    } else {
+        explicit_template.or_else(load_svg_template_path)
+    };
+    let template = match template_path {
+        Some(template_path) => {
+            let template = read_to_string(Path::new(&template_path))?;
+            validate_template(&template)
+                .map_err(|error| format!("{}: {}", template_path, error))?;
+            Some(template)
+        }
+        None => None,
+    };
+    if let Some(target) = targets
+        .iter()
+        .find(|target| !target.supports_css_variables())
+        && color_mode == ColorMode::Vars
+    {
+        return Err(format!(
+            "--colors=vars is not supported for the {} target.",
+            target.name()
+        ));
+    }
 
-    if parsed.options.get("help").is_some() {
-        println!("Usage: -svg2tsx <directory> [--ext=.svg] [--dry-run] [--force] [--no-move]");
+    if parsed.options.contains_key("help") {
+        println!(
+            "Usage: -svg2tsx <directory> [--ext=.svg] [--dry-run] [--force] [--no-move] [--colors=current|preserve|props|vars] [--optimize=all|none|LIST] [--precision=3] [--ids=use-id|prefix|keep] [--target=web,react-native,solid,vue,svelte] [--template=path] [--no-template] [--ref] [--memo] [--size[=24]] [--title] [--recursive] [--out-dir=path] [--watch] [--undo] [--check] [--case=pascal|camel] [--prefix=Icon] [--suffix=Icon] [--registry] [--lazy] [--lint] [--rules=LIST] [--dimensions=keep|remove|em]"
+        );
         return Ok(());
     }
 
@@ -27,6 +159,18 @@ pub fn svg_to_tsx(args: Vec<String>) -> Result<(), String> {
         return Ok(());
     }
 
+    if parsed.options.contains_key("undo") {
+        let output = out_dir
+            .as_deref()
+            .map(Path::new)
+            .unwrap_or(Path::new(&directory));
+        return undo_run(output, force);
+    }
+
+    if watch && (dry_run || check) {
+        return Err("--watch cannot be combined with --dry-run or --check.".to_string());
+    }
+
     let ext_list: Vec<String> = if !custom_extensions.is_empty() {
         custom_extensions
             .split(',')
@@ -36,87 +180,857 @@ pub fn svg_to_tsx(args: Vec<String>) -> Result<(), String> {
         vec![".svg".to_string()]
     };
 
-    let files = read_dir_and_sort(&directory, &ext_list);
+    let directory = Path::new(&directory);
+    let output = out_dir.as_deref().map(Path::new).unwrap_or(directory);
+    let options_hash = content_hash(&format!(
+        "{}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}",
+        env!("CARGO_PKG_VERSION"),
+        optimize_options,
+        id_mode,
+        color_mode,
+        dimension_mode,
+        targets,
+        template,
+        react_options,
+        name_options
+    ));
+    let options = ConvertOptions {
+        root: directory.to_path_buf(),
+        output_root: output.to_path_buf(),
+        options_hash,
+        dry_run: dry_run || check,
+        check,
+        force: force || check,
+        move_originals: !no_move && out_dir.is_none() && !watch && !check,
+        recursive,
+        registry,
+        lazy,
+        lint_rules,
+        ext_list,
+        optimize_options,
+        id_mode,
+        color_mode,
+        dimension_mode,
+        targets,
+        template,
+        react_options,
+        name_options,
+    };
+
+    let mut state = RunState {
+        manifest: Manifest::load(output)?,
+        journal: if dry_run || check {
+            Journal::preview()
+        } else {
+            Journal::default()
+        },
+        ..Default::default()
+    };
+    if let Err(error) = run_conversion(directory, output, &options, &mut state) {
+        if state.journal.has_changes() {
+            println!("svgToTsx - Failed, rolling back changes...");
+            state.journal.rollback()?;
+        }
+        return Err(error);
+    }
+    if !dry_run && !check {
+        state.journal.save(output)?;
+    }
+    let summary = &state.summary;
 
-    if files.is_empty() {
-        println!("No matching files found in {}", directory);
+    if check {
+        let changes = state.journal.planned_changes() + summary.stale;
+        if changes > 0 {
+            return Err(format!(
+                "svgToTsx - Check failed: {} generated file(s) are out of date.",
+                changes
+            ));
+        }
+        println!(
+            "svgToTsx - Check passed. {} icon(s) are up to date.",
+            summary.processed
+        );
         return Ok(());
     }
 
-    let dashed_attribute_regex =
-        Regex::new(r"(\w+)-(\w+)").map_err(|error| format!("Invalid regex: {}", error))?;
-    let fill_regex = Regex::new(r###"fill="(?!none)([^"\s]+)""###)
-        .map_err(|error| format!("Invalid regex: {}", error))?;
-    let stroke_hex_regex = Regex::new(r###"stroke="#([^"\s]+)""###)
-        .map_err(|error| format!("Invalid regex: {}", error))?;
-    let stroke_regex = Regex::new(r###"stroke="([^"\s]+)""###)
-        .map_err(|error| format!("Invalid regex: {}", error))?;
+    if summary.processed == 0 && !watch {
+        println!("No matching files found in {}", directory.display());
+        return Ok(());
+    }
+
+    if summary.unchanged > 0 {
+        println!(
+            "svgToTsx - {} file(s) unchanged since the last run.",
+            summary.unchanged
+        );
+    }
+    println!("svgToTsx - Done! Processed {} file(s).", summary.processed);
+    if watch {
+        watch_directory(directory, output, &options, &mut state)?;
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+struct RunState {
+    manifest: Manifest,
+    journal: Journal,
+    summary: ConvertSummary,
+}
+
+#[derive(Default)]
+struct ConvertSummary {
+    processed: usize,
+    unchanged: usize,
+    stale: usize,
+}
+
+#[derive(PartialEq)]
+enum ConvertStatus {
+    Written,
+    Unchanged,
+    Skipped,
+}
+
+struct ConvertOptions {
+    root: PathBuf,
+    output_root: PathBuf,
+    options_hash: String,
+    dry_run: bool,
+    check: bool,
+    force: bool,
+    move_originals: bool,
+    recursive: bool,
+    registry: bool,
+    lazy: bool,
+    lint_rules: Option<LintRules>,
+    ext_list: Vec<String>,
+    optimize_options: OptimizeOptions,
+    id_mode: IdMode,
+    color_mode: ColorMode,
+    dimension_mode: DimensionMode,
+    targets: Vec<Target>,
+    template: Option<String>,
+    react_options: ReactOptions,
+    name_options: NameOptions,
+}
+
+fn run_conversion(
+    directory: &Path,
+    output: &Path,
+    options: &ConvertOptions,
+    state: &mut RunState,
+) -> Result<(), String> {
+    if let Some(rules) = &options.lint_rules {
+        let files = source_files(directory, output, options)
+            .into_keys()
+            .collect::<Vec<_>>();
+        let (errors, _) = lint_files(&files, rules)?;
+        if errors > 0 {
+            return Err(format!(
+                "svgToTsx - Lint failed with {} error(s). Nothing was converted.",
+                errors
+            ));
+        }
+    }
+    convert_tree(directory, output, options, state)?;
+    state.summary.stale = report_stale(&mut state.manifest, options);
+    write_indexes(directory, output, options, &mut state.journal)?;
+    if !options.dry_run {
+        state.manifest.save(output, &mut state.journal)?;
+    }
+    state.journal.commit()
+}
+
+fn is_output_directory(
+    directory: &Path,
+    output: &Path,
+    subdirectory: &str,
+    options: &ConvertOptions,
+) -> bool {
+    subdirectory == "original"
+        || (directory == output
+            && options
+                .targets
+                .iter()
+                .any(|target| target.subdirectory() == subdirectory))
+        || is_same_directory(&directory.join(subdirectory), output)
+}
+
+fn is_same_directory(left: &Path, right: &Path) -> bool {
+    match (left.canonicalize(), right.canonicalize()) {
+        (Ok(left), Ok(right)) => left == right,
+        _ => false,
+    }
+}
+
+fn child_directories(directory: &Path, output: &Path, options: &ConvertOptions) -> Vec<String> {
+    if !options.recursive {
+        return Vec::new();
+    }
+    list_subdirectories(directory)
+        .into_iter()
+        .filter(|subdirectory| !is_output_directory(directory, output, subdirectory, options))
+        .collect()
+}
+
+fn convert_tree(
+    directory: &Path,
+    output: &Path,
+    options: &ConvertOptions,
+    state: &mut RunState,
+) -> Result<(), String> {
+    convert_directory(directory, output, options, state)?;
+
+    if options.recursive && directory == output {
+        for target in &options.targets {
+            let path = directory.join(target.subdirectory());
+            if !target.subdirectory().is_empty()
+                && path.is_dir()
+                && !read_dir_and_sort(&path.to_string_lossy(), &options.ext_list).is_empty()
+            {
+                println!(
+                    "Skipping {}: it is a target output directory.",
+                    path.display()
+                );
+            }
+        }
+    }
+
+    for subdirectory in child_directories(directory, output, options) {
+        convert_tree(
+            &directory.join(&subdirectory),
+            &output.join(&subdirectory),
+            options,
+            state,
+        )?;
+    }
+    Ok(())
+}
+
+fn write_indexes(
+    directory: &Path,
+    output: &Path,
+    options: &ConvertOptions,
+    journal: &mut Journal,
+) -> Result<(), String> {
+    for target in &options.targets {
+        write_target_indexes(directory, output, options, *target, journal)?;
+        if options.registry {
+            write_target_registry(directory, output, options, *target, journal)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_target_registry(
+    directory: &Path,
+    output: &Path,
+    options: &ConvertOptions,
+    target: Target,
+    journal: &mut Journal,
+) -> Result<(), String> {
+    let mut entries = Vec::new();
+    collect_registry_entries(
+        directory,
+        output,
+        "",
+        options,
+        target,
+        journal,
+        &mut entries,
+    );
+
+    let target_output = output.join(target.subdirectory());
+    let mut files = vec![(
+        target_output.join(REGISTRY_FILENAME),
+        registry_content(&entries, target),
+    )];
+    if options.lazy {
+        files.push((
+            target_output.join(LAZY_REGISTRY_FILENAME),
+            lazy_registry_content(&entries),
+        ));
+    }
+    for (path, content) in files {
+        if entries.is_empty() {
+            if path.exists() {
+                journal.remove_file(&path)?;
+            }
+            continue;
+        }
+        journal.ensure_dir(&target_output)?;
+        journal.write_string(&path, &content)?;
+    }
+    Ok(())
+}
+
+fn collect_registry_entries(
+    directory: &Path,
+    output: &Path,
+    relative: &str,
+    options: &ConvertOptions,
+    target: Target,
+    journal: &Journal,
+    entries: &mut Vec<RegistryEntry>,
+) {
+    let mut sources = read_dir_and_sort(&directory.to_string_lossy(), &options.ext_list);
+    sources.extend(original_files(directory, options));
+    sources.sort();
+    sources.dedup();
+    let (component_names, _) = unique_identifiers(&sources, &options.name_options);
+    let (kebab_names, _) = unique_kebab_names(&sources);
+    let keys = component_names
+        .into_iter()
+        .map(|name| name + target.extension())
+        .zip(kebab_names)
+        .collect::<BTreeMap<_, _>>();
+
+    let files = journal.list_files(
+        &output.join(target.subdirectory()),
+        &[target.extension().to_string()],
+    );
+    for file in files {
+        let name = keys
+            .get(&file)
+            .cloned()
+            .unwrap_or_else(|| kebab_case(file_stem(&file)));
+        let (key, path) = match (relative.is_empty(), target.subdirectory().is_empty()) {
+            (true, _) => (name, format!("./{}", file)),
+            (false, true) => (
+                format!("{}/{}", relative_key(relative), name),
+                format!("./{}/{}", relative, file),
+            ),
+            (false, false) => (
+                format!("{}/{}", relative_key(relative), name),
+                format!("../{}/{}/{}", relative, target.subdirectory(), file),
+            ),
+        };
+        if entries.iter().any(|entry| entry.key == key) {
+            println!(
+                "svgToTsx - Warning: {} also maps to icon name {}. Skipping it in the registry.",
+                path, key
+            );
+            continue;
+        }
+        let name = if relative.is_empty() {
+            file_stem(&file).to_string()
+        } else {
+            format!("{} {}", relative, file_stem(&file))
+        };
+        entries.push(RegistryEntry { key, name, path });
+    }
+
+    for subdirectory in child_directories(directory, output, options) {
+        let nested = if relative.is_empty() {
+            subdirectory.clone()
+        } else {
+            format!("{}/{}", relative, subdirectory)
+        };
+        collect_registry_entries(
+            &directory.join(&subdirectory),
+            &output.join(&subdirectory),
+            &nested,
+            options,
+            target,
+            journal,
+            entries,
+        );
+    }
+}
+
+fn relative_key(relative: &str) -> String {
+    relative
+        .split('/')
+        .map(kebab_case)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn write_target_indexes(
+    directory: &Path,
+    output: &Path,
+    options: &ConvertOptions,
+    target: Target,
+    journal: &mut Journal,
+) -> Result<bool, String> {
+    let mut namespaces = Vec::new();
+    for subdirectory in child_directories(directory, output, options) {
+        let written = write_target_indexes(
+            &directory.join(&subdirectory),
+            &output.join(&subdirectory),
+            options,
+            target,
+            journal,
+        )?;
+        if written {
+            let path = if target.subdirectory().is_empty() {
+                format!("./{}", subdirectory)
+            } else {
+                format!("../{}/{}", subdirectory, target.subdirectory())
+            };
+            namespaces.push((make_component_name(&subdirectory), path));
+        }
+    }
+    write_index(
+        &output.join(target.subdirectory()),
+        target.extension(),
+        &namespaces,
+        &NameOptions {
+            case: options.name_options.case,
+            ..Default::default()
+        },
+        journal,
+    )
+}
 
+fn convert_directory(
+    directory: &Path,
+    output: &Path,
+    options: &ConvertOptions,
+    state: &mut RunState,
+) -> Result<(), String> {
+    let files = read_dir_and_sort(&directory.to_string_lossy(), &options.ext_list);
+    let originals = original_files(directory, options);
+    let names = component_names(&files, &originals, options);
     for filename in &files {
-        let path = join_path(&directory, filename);
-        let mut content = read_to_string(&path)?;
-
-        let filename_no_ext = filename.split('.').next().unwrap_or("");
-        let component_name = make_component_name(filename_no_ext);
-
-        content = dashed_attribute_regex
-            .replace_all(&content, |captures: &regex::Captures| {
-                let first = captures.get(1).map(|value| value.as_str()).unwrap_or("");
-                let second = captures.get(2).map(|value| value.as_str()).unwrap_or("");
-                let mut uppercase = second.chars();
-                match uppercase.next() {
-                    Some(ch) => format!("{}{}{}", first, ch.to_uppercase(), uppercase.as_str()),
-                    None => format!("{}", first),
-                }
+        if convert_file(
+            directory,
+            output,
+            filename,
+            &names[filename],
+            options,
+            state,
+        )? == ConvertStatus::Unchanged
+        {
+            state.summary.unchanged += 1;
+        }
+    }
+    state.summary.processed += files.len();
+
+    if options.check && directory == output {
+        for filename in &originals {
+            if files.contains(filename) {
+                continue;
+            }
+            convert_file(
+                &directory.join("original"),
+                output,
+                filename,
+                &names[filename],
+                options,
+                state,
+            )?;
+            state.summary.processed += 1;
+        }
+    }
+    Ok(())
+}
+
+fn convert_file(
+    directory: &Path,
+    output: &Path,
+    filename: &str,
+    component_name: &str,
+    options: &ConvertOptions,
+    state: &mut RunState,
+) -> Result<ConvertStatus, String> {
+    let path = directory.join(filename);
+    let content = read_to_string(&path)?;
+    let key = manifest_key(&options.root, &path);
+    let source_hash = content_hash(&content);
+    let previous = state.manifest.icons.get(&key).cloned().unwrap_or_default();
+
+    let outputs = options
+        .targets
+        .iter()
+        .map(|target| {
+            let relative_path = Path::new(target.subdirectory()).join(format!(
+                "{}{}",
+                component_name,
+                target.extension()
+            ));
+            let output_path = output.join(&relative_path);
+            let output_key = manifest_key(&options.output_root, &output_path);
+            (*target, relative_path, output_path, output_key)
+        })
+        .collect::<Vec<_>>();
+
+    let recorded_outputs = outputs
+        .iter()
+        .map(|(_, _, output_path, output_key)| (output_key.clone(), output_path.clone()))
+        .collect::<Vec<_>>();
+    let up_to_date = !options.force
+        && previous.is_current(&source_hash, &options.options_hash, &recorded_outputs);
+    if up_to_date {
+        if options.dry_run {
+            println!("svgToTsx - Dry run. {} is up to date.", path.display());
+        }
+        move_original(directory, filename, options, &mut state.journal);
+        return Ok(ConvertStatus::Unchanged);
+    }
+
+    let mut document =
+        parse_svg(&content).map_err(|error| format!("{}: {}", path.display(), error))?;
+    optimize(&mut document, &options.optimize_options);
+    let Some(root) = document.root_mut() else {
+        return Ok(ConvertStatus::Skipped);
+    };
+    if !normalize_dimensions(root, options.dimension_mode) {
+        println!(
+            "svgToTsx - Warning: {} has no viewBox and no numeric width/height, so it will not scale.",
+            path.display()
+        );
+    }
+    let file_color_mode = match root.remove_attribute("data-cozy-colors") {
+        Some(value) => {
+            ColorMode::parse(&value).map_err(|error| format!("{}: {}", path.display(), error))?
+        }
+        None => options.color_mode,
+    };
+    if file_color_mode == ColorMode::Current {
+        root.visit_mut(&mut apply_current_color);
+    }
+    let palette = collect_palette(root);
+    let ids = collect_ids(root);
+
+    let mut jsx = element_to_jsx(root);
+    jsx.spreads.push("props".to_string());
+    bind_colors(&mut jsx, &palette, file_color_mode);
+    let id_mode = match options
+        .targets
+        .iter()
+        .find(|target| !target.supports_style_expressions())
+    {
+        Some(target) if options.id_mode == IdMode::UseId && styles_reference_ids(root, &ids) => {
+            println!(
+                "svgToTsx - Warning: {} references ids from <style>, which the {} target cannot scope with --ids=use-id. Prefixing its ids with the component name instead.",
+                path.display(),
+                target.name()
+            );
+            IdMode::Prefix
+        }
+        _ => options.id_mode,
+    };
+    scope_ids(&mut jsx, &ids, id_mode, component_name);
+
+    let unique_ids = id_mode == IdMode::UseId && !ids.is_empty();
+    let props = if file_color_mode == ColorMode::Props {
+        palette
+            .iter()
+            .enumerate()
+            .map(|(index, color)| ComponentProp {
+                name: color_prop_name(index),
+                type_name: "string".to_string(),
+                default: Some(json_string(color)),
             })
-            .to_string();
-
-        content = fill_regex
-            .replace_all(&content, "fill=\"currentColor\"")
-            .to_string();
-        content = stroke_hex_regex
-            .replace_all(&content, "stroke=\"currentColor\"")
-            .to_string();
-        content = stroke_regex
-            .replace_all(&content, "stroke=\"currentColor\"")
-            .to_string();
-        content = content.replace("class=\"", "className=\"");
-        content = content.replace("clip-rule=\"", "clipRule=\"");
-        content = content.replace("fill-rule=\"", "fillRule=\"");
-        content = content.replace("stroke-linecap=\"", "strokeLinecap=\"");
-        content = content.replace("stroke-linejoin=\"", "strokeLinejoin=\"");
-        content = content.replace("stroke-width=\"", "strokeWidth=\"");
-
-        let component_content = component_template(&component_name, &content);
-        let output_path = Path::new(&directory).join(format!("{}.tsx", component_name));
-
-        if output_path.exists() && !force {
-            println!("File {}.tsx already exists. Skipping...", component_name);
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut written = false;
+    let mut entry = ManifestEntry {
+        source_hash,
+        options_hash: options.options_hash.clone(),
+        outputs: BTreeMap::new(),
+    };
+    for (target, relative_path, output_path, output_key) in &outputs {
+        if file_color_mode == ColorMode::Vars && !target.supports_css_variables() {
+            return Err(format!(
+                "{}: --colors=vars is not supported for the {} target.",
+                path.display(),
+                target.name()
+            ));
+        }
+        let component = Component {
+            name: component_name.to_string(),
+            filename: filename.to_string(),
+            template: options.template.clone(),
+            props: props.clone(),
+            ..Default::default()
+        };
+
+        if output_path.exists() && !options.force {
+            match previous.outputs.get(output_key) {
+                Some(recorded) if file_hash(output_path).as_ref() == Some(recorded) => {}
+                Some(recorded) => {
+                    println!(
+                        "File {} was edited by hand. Skipping... (use --force to overwrite)",
+                        relative_path.display()
+                    );
+                    entry.outputs.insert(output_key.clone(), recorded.clone());
+                    continue;
+                }
+                None => {
+                    println!(
+                        "File {} already exists. Skipping...",
+                        relative_path.display()
+                    );
+                    continue;
+                }
+            }
+        }
+
+        let rendered = target.render(&jsx, &component, unique_ids, &options.react_options);
+        state
+            .journal
+            .ensure_dir(output_path.parent().unwrap_or(output))?;
+        state.journal.write_string(output_path, &rendered)?;
+        entry
+            .outputs
+            .insert(output_key.clone(), content_hash(&rendered));
+        written = true;
+    }
+
+    if !entry.outputs.is_empty() {
+        state.manifest.icons.insert(key, entry);
+    }
+    if !written {
+        return Ok(ConvertStatus::Skipped);
+    }
+    move_original(directory, filename, options, &mut state.journal);
+    Ok(ConvertStatus::Written)
+}
+
+fn move_original(
+    directory: &Path,
+    filename: &str,
+    options: &ConvertOptions,
+    journal: &mut Journal,
+) {
+    if !options.move_originals {
+        return;
+    }
+    let new_svg_path = directory.join("original").join(filename);
+    let old_svg_path = directory.join(filename);
+    journal.stage_move(&old_svg_path, &new_svg_path);
+}
+
+fn report_stale(manifest: &mut Manifest, options: &ConvertOptions) -> usize {
+    let mut stale = 0;
+    let mut removed = Vec::new();
+    for (key, entry) in &manifest.icons {
+        let source = options.root.join(key);
+        let original = source
+            .parent()
+            .zip(source.file_name())
+            .map(|(parent, filename)| parent.join("original").join(filename));
+        if source.exists() || original.is_some_and(|original| original.exists()) {
             continue;
         }
 
-        if dry_run {
-            println!("svgToTsx - Dry run. Would write {}", output_path.display());
+        let existing = entry
+            .outputs
+            .keys()
+            .filter(|output_key| options.output_root.join(output_key).exists())
+            .collect::<Vec<_>>();
+        if existing.is_empty() {
+            removed.push(key.clone());
+        }
+        stale += existing.len();
+        for output_key in existing {
+            println!(
+                "Stale component {}: source {} no longer exists.",
+                output_key, key
+            );
+        }
+    }
+    for key in removed {
+        manifest.icons.remove(&key);
+    }
+    stale
+}
+
+fn original_files(directory: &Path, options: &ConvertOptions) -> Vec<String> {
+    let originals = directory.join("original");
+    if !originals.is_dir() {
+        return Vec::new();
+    }
+    read_dir_and_sort(&originals.to_string_lossy(), &options.ext_list)
+}
+
+fn component_names(
+    files: &[String],
+    originals: &[String],
+    options: &ConvertOptions,
+) -> BTreeMap<String, String> {
+    let mut files = [files, originals].concat();
+    files.sort();
+    files.dedup();
+    let (names, warnings) = unique_identifiers(&files, &options.name_options);
+    for warning in warnings {
+        println!("svgToTsx - Warning: {}", warning);
+    }
+    files.into_iter().zip(names).collect()
+}
+
+fn source_files(
+    directory: &Path,
+    output: &Path,
+    options: &ConvertOptions,
+) -> BTreeMap<PathBuf, (Option<SystemTime>, u64)> {
+    let mut files = BTreeMap::new();
+    if !directory.is_dir() {
+        return files;
+    }
+    for filename in read_dir_and_sort(&directory.to_string_lossy(), &options.ext_list) {
+        let path = directory.join(&filename);
+        if let Ok(metadata) = fs::metadata(&path) {
+            files.insert(path, (metadata.modified().ok(), metadata.len()));
+        }
+    }
+    for subdirectory in child_directories(directory, output, options) {
+        files.extend(source_files(
+            &directory.join(&subdirectory),
+            &output.join(&subdirectory),
+            options,
+        ));
+    }
+    files
+}
+
+fn watch_directory(
+    directory: &Path,
+    output: &Path,
+    options: &ConvertOptions,
+    state: &mut RunState,
+) -> Result<(), String> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)
+        .map_err(|error| format!("Failed to start the file watcher: {}", error))?;
+    let mode = if options.recursive {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+    watcher
+        .watch(directory, mode)
+        .map_err(|error| format!("Failed to watch {}: {}", directory.display(), error))?;
+    println!(
+        "svgToTsx - Watching {} for changes. Press Ctrl+C to stop.",
+        directory.display()
+    );
+    let mut snapshot = source_files(directory, output, options);
+
+    while let Ok(event) = receiver.recv() {
+        let mut relevant = is_source_event(event, options);
+        while let Ok(event) = receiver.recv_timeout(WATCH_DEBOUNCE) {
+            relevant |= is_source_event(event, options);
+        }
+        if !relevant {
+            continue;
+        }
+        let current = source_files(directory, output, options);
+        if current == snapshot {
             continue;
         }
 
-        write_string(&output_path, &component_content)?;
+        for (path, stamp) in &current {
+            if snapshot.get(path) == Some(stamp) {
+                continue;
+            }
+            if let Some(rules) = &options.lint_rules {
+                match lint_files(std::slice::from_ref(path), rules) {
+                    Ok((0, _)) => {}
+                    Ok((errors, _)) => {
+                        println!(
+                            "svgToTsx - Skipped {}: {} lint error(s).",
+                            path.display(),
+                            errors
+                        );
+                        continue;
+                    }
+                    Err(error) => {
+                        println!("svgToTsx - {}", error);
+                        continue;
+                    }
+                }
+            }
+            let (source, destination, filename) = split_source_path(directory, output, path);
+            let mut files = read_dir_and_sort(&source.to_string_lossy(), &options.ext_list);
+            if !files.contains(&filename) {
+                files.push(filename.clone());
+            }
+            let names = component_names(&files, &original_files(&source, options), options);
+            match convert_file(
+                &source,
+                &destination,
+                &filename,
+                &names[&filename],
+                options,
+                state,
+            ) {
+                Ok(ConvertStatus::Written) => {
+                    println!("svgToTsx - Converted {}", path.display())
+                }
+                Ok(_) => {}
+                Err(error) => println!("svgToTsx - {}", error),
+            }
+        }
 
-        if !no_move {
-            let new_svg_path = Path::new(&directory).join("original").join(filename);
-            let old_svg_path = Path::new(&directory).join(filename);
-            ensure_dir(new_svg_path.parent().unwrap_or(Path::new(&directory)))?;
-            move_file(&old_svg_path, &new_svg_path)?;
+        for path in snapshot.keys().filter(|path| !current.contains_key(*path)) {
+            let Some(entry) = state
+                .manifest
+                .icons
+                .remove(&manifest_key(&options.root, path))
+            else {
+                continue;
+            };
+            for output_key in entry.outputs.keys() {
+                let component_path = options.output_root.join(output_key);
+                if component_path.exists() {
+                    state.journal.remove_file(&component_path)?;
+                    println!("svgToTsx - Removed {}", component_path.display());
+                }
+            }
         }
+
+        write_indexes(directory, output, options, &mut state.journal)?;
+        state.manifest.save(output, &mut state.journal)?;
+        state.journal.commit()?;
+        state.journal.save(output)?;
+        snapshot = current;
     }
+    Ok(())
+}
 
-    if !dry_run {
-        any_to_export(
-            vec![".tsx"],
-            vec![directory.clone(), "index.ts".to_string()],
-        )?;
+fn is_source_event(event: notify::Result<Event>, options: &ConvertOptions) -> bool {
+    match event {
+        Ok(event) => event.paths.iter().any(|path| {
+            path.is_dir()
+                || path.file_name().is_some_and(|name| {
+                    matches_extension(&name.to_string_lossy(), &options.ext_list)
+                })
+        }),
+        Err(error) => {
+            println!("svgToTsx - Watch error: {}", error);
+            false
+        }
     }
+}
 
-    println!("svgToTsx - Done! Processed {} file(s).", files.len());
+fn split_source_path(directory: &Path, output: &Path, path: &Path) -> (PathBuf, PathBuf, String) {
+    let source = path.parent().unwrap_or(directory).to_path_buf();
+    let relative = source.strip_prefix(directory).unwrap_or(Path::new(""));
+    let filename = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    (source.clone(), output.join(relative), filename)
+}
+
+fn undo_run(output: &Path, force: bool) -> Result<(), String> {
+    let Some(mut journal) = Journal::load(output)? else {
+        println!("svgToTsx - Nothing to undo in {}", output.display());
+        return Ok(());
+    };
+
+    remove_file(&output.join(JOURNAL_FILENAME))?;
+    if let Err(error) = journal.undo(force) {
+        journal.save(output)?;
+        return Err(error);
+    }
+    println!("svgToTsx - Undo complete.");
     Ok(())
 }
@@ -28,6 +28,9 @@ fn main() {
 
     let result = match command.as_str() {
         "-svg2tsx" => commands::svg_to_tsx::svg_to_tsx(rest),
+        "-svg2sprite" => commands::svg_to_sprite::svg_to_sprite(rest),
+        "-svggallery" => commands::svg_gallery::svg_gallery(rest),
+        "-svglint" => commands::svg_lint::svg_lint(rest),
         "-img2export" => commands::any_to_export::any_to_export(
             vec![".svg", ".jpg", ".jpeg", ".png", ".gif", ".webp", ".tsx"],
             rest,
@@ -44,5 +47,6 @@ fn main() {
 
     if let Err(error) = result {
         println!("{}", error);
+        std::process::exit(1);
     }
 }
@@ -0,0 +1,194 @@
+use crate::utils::jsx::{JsxElement, JsxNode, JsxValue, jsx_attribute_name};
+use crate::utils::svg::{Element, format_style, parse_style};
+
+const COLOR_PROPERTIES: &[&str] = &[
+    "fill",
+    "stroke",
+    "stop-color",
+    "flood-color",
+    "lighting-color",
+];
+const COLOR_PROP_NAMES: &[&str] = &["primaryColor", "secondaryColor", "tertiaryColor"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorMode {
+    Current,
+    Preserve,
+    Props,
+    Vars,
+}
+
+impl ColorMode {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "current" => Ok(ColorMode::Current),
+            "preserve" => Ok(ColorMode::Preserve),
+            "props" => Ok(ColorMode::Props),
+            "vars" => Ok(ColorMode::Vars),
+            _ => Err(format!(
+                "Unsupported color mode '{}'. Use 'current', 'preserve', 'props', or 'vars'.",
+                value
+            )),
+        }
+    }
+}
+
+fn is_palette_color(value: &str) -> bool {
+    let lower = value.trim().to_lowercase();
+    !lower.is_empty()
+        && !lower.starts_with("url(")
+        && !lower.starts_with("var(")
+        && !matches!(
+            lower.as_str(),
+            "none" | "currentcolor" | "inherit" | "transparent" | "context-fill" | "context-stroke"
+        )
+}
+
+pub fn normalize_color(value: &str) -> String {
+    let lower = value.trim().to_lowercase();
+    match lower.strip_prefix('#') {
+        Some(hex) if hex.len() == 3 || hex.len() == 4 => {
+            let expanded = hex.chars().flat_map(|ch| [ch, ch]).collect::<String>();
+            format!("#{}", expanded)
+        }
+        _ => lower,
+    }
+}
+
+fn is_current_color_target(name: &str, value: &str) -> bool {
+    (name == "fill" || name == "stroke") && value != "none" && !value.starts_with("url(")
+}
+
+pub fn apply_current_color(element: &mut Element) {
+    for (name, value) in element.attributes.iter_mut() {
+        if is_current_color_target(name, value) {
+            *value = "currentColor".to_string();
+        }
+
+        if name == "style" {
+            let mut declarations = parse_style(value);
+            for (property, declaration) in declarations.iter_mut() {
+                if is_current_color_target(property, declaration) {
+                    *declaration = "currentColor".to_string();
+                }
+            }
+            *value = format_style(&declarations);
+        }
+    }
+}
+
+pub fn collect_palette(root: &Element) -> Vec<String> {
+    let mut palette: Vec<String> = Vec::new();
+    let mut add = |value: &str| {
+        let color = normalize_color(value);
+        if is_palette_color(value) && !palette.contains(&color) {
+            palette.push(color);
+        }
+    };
+
+    root.visit(&mut |element| {
+        for (name, value) in &element.attributes {
+            if COLOR_PROPERTIES.contains(&name.as_str()) {
+                add(value);
+            }
+            if name == "style" {
+                for (property, declaration) in parse_style(value) {
+                    if COLOR_PROPERTIES.contains(&property.as_str()) {
+                        add(&declaration);
+                    }
+                }
+            }
+        }
+    });
+
+    palette
+}
+
+pub fn color_prop_name(index: usize) -> String {
+    COLOR_PROP_NAMES
+        .get(index)
+        .map(|name| name.to_string())
+        .unwrap_or_else(|| format!("color{}", index + 1))
+}
+
+fn bound_value(value: &str, palette: &[String], mode: ColorMode) -> Option<JsxValue> {
+    if !is_palette_color(value) {
+        return None;
+    }
+    let index = palette
+        .iter()
+        .position(|color| *color == normalize_color(value))?;
+    match mode {
+        ColorMode::Props => Some(JsxValue::Expression(color_prop_name(index))),
+        ColorMode::Vars => Some(JsxValue::String(format!(
+            "var(--icon-color-{}, {})",
+            index + 1,
+            value.trim()
+        ))),
+        ColorMode::Current | ColorMode::Preserve => None,
+    }
+}
+
+pub fn bind_colors(element: &mut JsxElement, palette: &[String], mode: ColorMode) {
+    let mut moved_to_style = Vec::new();
+    let mut attributes = Vec::new();
+
+    for (name, value) in element.attributes.drain(..) {
+        let property = COLOR_PROPERTIES
+            .iter()
+            .find(|property| jsx_attribute_name(property) == name);
+        let bound = match (&value, property) {
+            (JsxValue::String(text), Some(_)) => bound_value(text, palette, mode),
+            _ => None,
+        };
+
+        match (bound, property) {
+            (Some(bound), Some(property)) if mode == ColorMode::Vars => {
+                moved_to_style.push((property.to_string(), bound));
+            }
+            (Some(bound), _) => attributes.push((name, bound)),
+            (None, _) => attributes.push((name, value)),
+        }
+    }
+    element.attributes = attributes;
+
+    for (_, value) in element.attributes.iter_mut() {
+        if let JsxValue::Style(entries) = value {
+            for (property, entry) in entries.iter_mut() {
+                if !COLOR_PROPERTIES.contains(&property.as_str()) {
+                    continue;
+                }
+                if let JsxValue::String(text) = entry
+                    && let Some(bound) = bound_value(text, palette, mode)
+                {
+                    *entry = bound;
+                }
+            }
+        }
+    }
+
+    if !moved_to_style.is_empty() {
+        let style = element
+            .attributes
+            .iter_mut()
+            .find(|(name, _)| name == "style");
+        match style {
+            Some((_, JsxValue::Style(entries))) => {
+                for (property, value) in moved_to_style {
+                    if !entries.iter().any(|(existing, _)| *existing == property) {
+                        entries.push((property, value));
+                    }
+                }
+            }
+            _ => element
+                .attributes
+                .push(("style".to_string(), JsxValue::Style(moved_to_style))),
+        }
+    }
+
+    for child in element.children.iter_mut() {
+        if let JsxNode::Element(child) = child {
+            bind_colors(child, palette, mode);
+        }
+    }
+}
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 pub struct CozyConfig {
     pub gemini_api_key: Option<String>,
     pub backend: Option<String>,
+    pub svg_template: Option<String>,
 }
 
 fn load_config() -> Option<CozyConfig> {
@@ -15,16 +16,16 @@ fn load_config() -> Option<CozyConfig> {
 }
 
 fn resolve_home_dir() -> Option<PathBuf> {
-    if let Ok(home) = env::var("HOME") {
-        if !home.is_empty() {
-            return Some(PathBuf::from(home));
-        }
+    if let Ok(home) = env::var("HOME")
+        && !home.is_empty()
+    {
+        return Some(PathBuf::from(home));
     }
 
-    if let Ok(home) = env::var("USERPROFILE") {
-        if !home.is_empty() {
-            return Some(PathBuf::from(home));
-        }
+    if let Ok(home) = env::var("USERPROFILE")
+        && !home.is_empty()
+    {
+        return Some(PathBuf::from(home));
     }
 
     None
@@ -62,6 +63,14 @@ pub fn load_default_backend() -> Option<String> {
         .filter(|value| !value.is_empty())
 }
 
+pub fn load_svg_template_path() -> Option<String> {
+    let config = load_config()?;
+    config
+        .svg_template
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
 pub fn load_gemini_api_key() -> Result<String, String> {
     env::var("GEMINI_API_KEY")
         .ok()
@@ -75,6 +84,7 @@ pub fn load_gemini_api_key() -> Result<String, String> {
 pub fn update_config(
     api_key: Option<Option<&str>>,
     backend: Option<Option<&str>>,
+    svg_template: Option<Option<&str>>,
 ) -> Result<PathBuf, String> {
     let path = config_path().ok_or_else(|| "Failed to resolve home directory.".to_string())?;
     if let Some(parent) = path.parent() {
@@ -94,6 +104,11 @@ pub fn update_config(
             Some(None) => None,
             None => existing.backend,
         },
+        svg_template: match svg_template {
+            Some(Some(value)) => Some(value.to_string()),
+            Some(None) => None,
+            None => existing.svg_template,
+        },
     };
     let content = serde_json::to_string_pretty(&config)
         .map_err(|error| format!("Failed to serialize config: {}", error))?;
@@ -103,5 +118,5 @@ pub fn update_config(
 }
 
 pub fn write_config(api_key: Option<&str>, backend: Option<&str>) -> Result<PathBuf, String> {
-    update_config(Some(api_key), Some(backend))
+    update_config(Some(api_key), Some(backend), None)
 }
@@ -0,0 +1,197 @@
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Operation {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+fn diff_lines(old: &[&str], new: &[&str]) -> Vec<Operation> {
+    let prefix = old
+        .iter()
+        .zip(new.iter())
+        .take_while(|(left, right)| left == right)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(left, right)| left == right)
+        .count();
+    let old_middle = &old[prefix..old.len() - suffix];
+    let new_middle = &new[prefix..new.len() - suffix];
+
+    let columns = new_middle.len() + 1;
+    let mut lengths = vec![0u32; (old_middle.len() + 1) * columns];
+    for row in (0..old_middle.len()).rev() {
+        for column in (0..new_middle.len()).rev() {
+            lengths[row * columns + column] = if old_middle[row] == new_middle[column] {
+                lengths[(row + 1) * columns + column + 1] + 1
+            } else {
+                lengths[(row + 1) * columns + column].max(lengths[row * columns + column + 1])
+            };
+        }
+    }
+
+    let mut operations = (0..prefix)
+        .map(|index| Operation::Equal(index, index))
+        .collect::<Vec<_>>();
+    let (mut row, mut column) = (0, 0);
+    while row < old_middle.len() || column < new_middle.len() {
+        if row < old_middle.len()
+            && column < new_middle.len()
+            && old_middle[row] == new_middle[column]
+        {
+            operations.push(Operation::Equal(prefix + row, prefix + column));
+            row += 1;
+            column += 1;
+        } else if column < new_middle.len()
+            && (row == old_middle.len()
+                || lengths[row * columns + column + 1] > lengths[(row + 1) * columns + column])
+        {
+            operations.push(Operation::Insert(prefix + column));
+            column += 1;
+        } else {
+            operations.push(Operation::Delete(prefix + row));
+            row += 1;
+        }
+    }
+    operations.extend(
+        (0..suffix)
+            .map(|index| Operation::Equal(old.len() - suffix + index, new.len() - suffix + index)),
+    );
+    operations
+}
+
+fn hunk_range(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, count),
+    }
+}
+
+pub fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    let old_lines = old.lines().collect::<Vec<_>>();
+    let new_lines = new.lines().collect::<Vec<_>>();
+    let operations = diff_lines(&old_lines, &new_lines);
+    let changes = operations
+        .iter()
+        .enumerate()
+        .filter(|(_, operation)| !matches!(operation, Operation::Equal(..)))
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    if changes.is_empty() {
+        return String::new();
+    }
+
+    let mut groups: Vec<(usize, usize)> = Vec::new();
+    for index in changes {
+        let start = index.saturating_sub(CONTEXT_LINES);
+        let end = (index + CONTEXT_LINES + 1).min(operations.len());
+        match groups.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => groups.push((start, end)),
+        }
+    }
+
+    let mut output = format!("--- {}\n+++ {}\n", old_label, new_label);
+    for (start, end) in groups {
+        let hunk = &operations[start..end];
+        let old_start = hunk
+            .iter()
+            .find_map(|operation| match operation {
+                Operation::Equal(old, _) | Operation::Delete(old) => Some(*old),
+                Operation::Insert(_) => None,
+            })
+            .unwrap_or_else(|| {
+                operations[..start]
+                    .iter()
+                    .filter(|operation| !matches!(operation, Operation::Insert(_)))
+                    .count()
+            });
+        let new_start = hunk
+            .iter()
+            .find_map(|operation| match operation {
+                Operation::Equal(_, new) | Operation::Insert(new) => Some(*new),
+                Operation::Delete(_) => None,
+            })
+            .unwrap_or_else(|| {
+                operations[..start]
+                    .iter()
+                    .filter(|operation| !matches!(operation, Operation::Delete(_)))
+                    .count()
+            });
+        let old_count = hunk
+            .iter()
+            .filter(|operation| !matches!(operation, Operation::Insert(_)))
+            .count();
+        let new_count = hunk
+            .iter()
+            .filter(|operation| !matches!(operation, Operation::Delete(_)))
+            .count();
+
+        output.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(old_start, old_count),
+            hunk_range(new_start, new_count)
+        ));
+        for operation in hunk {
+            let line = match operation {
+                Operation::Equal(old, _) => format!(" {}", old_lines[*old]),
+                Operation::Delete(old) => format!("-{}", old_lines[*old]),
+                Operation::Insert(new) => format!("+{}", new_lines[*new]),
+            };
+            output.push_str(&line);
+            output.push('\n');
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_content_has_no_diff() {
+        assert_eq!(unified_diff("a\nb\n", "a\nb\n", "old", "new"), "");
+    }
+
+    #[test]
+    fn diffs_a_changed_line_with_context() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n";
+        let new = "1\n2\n3\n4\nfive\n6\n7\n8\n";
+        assert_eq!(
+            unified_diff(old, new, "a.ts", "a.ts"),
+            "--- a.ts\n+++ a.ts\n@@ -2,7 +2,7 @@\n 2\n 3\n 4\n-5\n+five\n 6\n 7\n 8\n"
+        );
+    }
+
+    #[test]
+    fn diffs_a_new_file() {
+        assert_eq!(
+            unified_diff("", "a\nb\n", "/dev/null", "b.ts"),
+            "--- /dev/null\n+++ b.ts\n@@ -0,0 +1,2 @@\n+a\n+b\n"
+        );
+    }
+
+    #[test]
+    fn splits_distant_changes_into_hunks() {
+        let old = (1..=20)
+            .map(|line| format!("{}\n", line))
+            .collect::<String>();
+        let new = (1..=20)
+            .map(|line| match line {
+                2 => "two\n".to_string(),
+                19 => "nineteen\n".to_string(),
+                _ => format!("{}\n", line),
+            })
+            .collect::<String>();
+        let diff = unified_diff(&old, &new, "old", "new");
+        assert_eq!(diff.matches("@@ -").count(), 2, "{}", diff);
+        assert!(diff.contains("@@ -1,5 +1,5 @@\n 1\n-2\n+two\n"), "{}", diff);
+        assert!(diff.contains("@@ -16,5 +16,5 @@\n"), "{}", diff);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::utils::svg::Element;
+
+const EM_SIZE: &str = "1em";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DimensionMode {
+    Keep,
+    Remove,
+    Em,
+}
+
+impl DimensionMode {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "keep" => Ok(DimensionMode::Keep),
+            "remove" => Ok(DimensionMode::Remove),
+            "em" | "1em" => Ok(DimensionMode::Em),
+            _ => Err(format!(
+                "Unsupported dimensions mode '{}'. Use 'keep', 'remove', or 'em'.",
+                value
+            )),
+        }
+    }
+}
+
+fn pixel_size(value: &str) -> Option<String> {
+    let value = value.strip_suffix("px").unwrap_or(value).trim();
+    value.parse::<f64>().ok().map(|_| value.to_string())
+}
+
+pub fn view_box(root: &Element) -> Option<String> {
+    if let Some(view_box) = root.attribute("viewBox") {
+        return Some(view_box.trim().to_string());
+    }
+    let width = pixel_size(root.attribute("width")?.trim())?;
+    let height = pixel_size(root.attribute("height")?.trim())?;
+    Some(format!("0 0 {} {}", width, height))
+}
+
+pub fn normalize_dimensions(root: &mut Element, mode: DimensionMode) -> bool {
+    let view_box = view_box(root);
+    let scalable = view_box.is_some();
+    if let Some(view_box) = view_box
+        && root.attribute("viewBox").is_none()
+    {
+        let index = root
+            .attributes
+            .iter()
+            .position(|(name, _)| name == "width" || name == "height")
+            .unwrap_or(root.attributes.len());
+        root.attributes
+            .insert(index, ("viewBox".to_string(), view_box));
+    }
+
+    match mode {
+        DimensionMode::Keep => {}
+        DimensionMode::Remove => root
+            .attributes
+            .retain(|(name, _)| name != "width" && name != "height"),
+        DimensionMode::Em => {
+            root.set_attribute("width", EM_SIZE);
+            root.set_attribute("height", EM_SIZE);
+        }
+    }
+    scalable
+}
@@ -1,8 +1,7 @@
 use std::fs;
+use std::io;
 use std::path::{Path, PathBuf};
 
-use regex::Regex;
-
 pub fn read_dir_and_sort(path: &str, ext_filter: &[String]) -> Vec<String> {
     let metadata = match fs::metadata(path) {
         Ok(value) => value,
@@ -40,76 +39,211 @@ pub fn read_dir_and_sort(path: &str, ext_filter: &[String]) -> Vec<String> {
     entries
 }
 
-pub fn make_component_name(filename: &str) -> String {
-    let sanitized = filename.replace(' ', "");
-    sanitized
-        .split('-')
-        .map(|section| {
-            let mut chars = section.chars();
-            match chars.next() {
-                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
-                None => String::new(),
-            }
-        })
-        .collect::<String>()
+#[derive(Debug, Clone)]
+pub struct ComponentProp {
+    pub name: String,
+    pub type_name: String,
+    pub default: Option<String>,
 }
 
-pub fn component_template(component_name: &str, content: &str) -> String {
-    let template = format!(
-        "\
-import React from \"react\";\n\nfunction {component_name}(props: React.JSX.IntrinsicElements[\"svg\"]) {{\n  return (\n    {content}\n  );\n}}\n\nexport default {component_name};\n"
-    );
-    format_svg_component(&template)
+pub const WEB_PROPS_TYPE: &str = "React.JSX.IntrinsicElements[\"svg\"]";
+
+#[derive(Debug, Clone)]
+pub struct Component {
+    pub name: String,
+    pub filename: String,
+    pub template: Option<String>,
+    pub content: String,
+    pub imports: Vec<String>,
+    pub props_type: String,
+    pub props: Vec<ComponentProp>,
+    pub statements: Vec<String>,
+    pub ref_type: Option<String>,
+    pub memo: bool,
 }
 
-pub fn format_svg_component(content: &str) -> String {
-    let has_props = content.contains("{...props}");
-    let mut formatted = content.replace("\r\n", "\n");
-    let space_re = Regex::new(r" {2,}").unwrap();
-    let semicolon_re = Regex::new(r";+\n").unwrap();
-    let newline_re = Regex::new(r"\n{2,}").unwrap();
-    let svg_re = Regex::new(r"<svg([^>]*)>").unwrap();
+impl Default for Component {
+    fn default() -> Self {
+        Component {
+            name: String::new(),
+            filename: String::new(),
+            template: None,
+            content: String::new(),
+            imports: vec!["import React from \"react\";".to_string()],
+            props_type: WEB_PROPS_TYPE.to_string(),
+            props: Vec::new(),
+            statements: Vec::new(),
+            ref_type: None,
+            memo: false,
+        }
+    }
+}
 
-    formatted = formatted
-        .lines()
-        .filter(|line| !line.trim().is_empty())
-        .map(|line| line.trim_end())
-        .collect::<Vec<_>>()
-        .join("\n");
-
-    formatted = formatted.replace("{...props}", "{...props}");
-    formatted = formatted.replace("\t", "  ");
-    formatted = space_re.replace_all(&formatted, "  ").to_string();
-    formatted = semicolon_re.replace_all(&formatted, ";\n").to_string();
-    formatted = newline_re.replace_all(&formatted, "\n\n").to_string();
-
-    if !has_props {
-        formatted = svg_re
-            .replacen(&formatted, 1, "<svg$1 {...props}>")
-            .to_string();
+pub fn component_template(component: &Component) -> String {
+    let component_name = &component.name;
+    let content = &component.content;
+    let props_type = &component.props_type;
+    let signature = if component.props.is_empty() {
+        format!("props: {props_type}")
+    } else {
+        let bindings = component
+            .props
+            .iter()
+            .map(|prop| match &prop.default {
+                Some(default) => format!("  {} = {},\n", prop.name, default),
+                None => format!("  {},\n", prop.name),
+            })
+            .collect::<String>();
+        let types = component
+            .props
+            .iter()
+            .map(|prop| format!("  {}?: {};\n", prop.name, prop.type_name))
+            .collect::<String>();
+        format!("{{\n{bindings}  ...props\n}}: {props_type} & {{\n{types}}}")
+    };
+    let signature = match &component.ref_type {
+        Some(ref_type) => format!("{signature}, ref: React.Ref<{ref_type}>"),
+        None => signature,
+    };
+    let mut exported = component_name.clone();
+    let mut exports = String::new();
+    if component.ref_type.is_some() {
+        exports.push_str(&format!(
+            "const ForwardRef = React.forwardRef({exported});\n"
+        ));
+        exported = "ForwardRef".to_string();
     }
+    if component.memo {
+        exports.push_str(&format!("const Memo = React.memo({exported});\n"));
+        exported = "Memo".to_string();
+    }
+    if !exports.is_empty() {
+        exports.push('\n');
+    }
+    exports.push_str(&format!("export default {exported};"));
+    let statements = component
+        .statements
+        .iter()
+        .map(|statement| format!("  {}\n", statement))
+        .collect::<String>();
+    let imports = component.imports.join("\n");
 
-    let lines: Vec<&str> = formatted.lines().collect();
-    let mut indented: Vec<String> = Vec::new();
-    let mut indent_level = 0usize;
+    if let Some(template) = &component.template {
+        let full_props_type = if component.props.is_empty() {
+            props_type.clone()
+        } else {
+            let types = component
+                .props
+                .iter()
+                .map(|prop| format!("{}?: {};", prop.name, prop.type_name))
+                .collect::<Vec<_>>()
+                .join(" ");
+            format!("{props_type} & {{ {types} }}")
+        };
+        return fill_template(
+            template,
+            &[
+                ("componentName", component_name),
+                ("exports", &exports),
+                ("filename", &component.filename),
+                ("imports", &imports),
+                ("params", &signature),
+                ("propsType", &full_props_type),
+                ("statements", &dedent(&statements)),
+                ("svg", &dedent(content)),
+            ],
+        );
+    }
 
-    for line in lines {
-        let trimmed_line = line.trim();
+    format!(
+        "\
+{imports}\n\nfunction {component_name}({signature}) {{\n{statements}  return (\n{content}\n  );\n}}\n\n{exports}\n"
+    )
+}
 
-        if trimmed_line.starts_with("</") {
-            indent_level = indent_level.saturating_sub(1);
-        }
+pub const TEMPLATE_PLACEHOLDERS: &[&str] = &[
+    "componentName",
+    "exports",
+    "filename",
+    "imports",
+    "params",
+    "propsType",
+    "statements",
+    "svg",
+];
 
-        indented.push(format!("{}{}", "  ".repeat(indent_level), trimmed_line));
+pub fn validate_template(template: &str) -> Result<(), String> {
+    if !template.contains("{{svg}}") {
+        return Err("Component template must contain a {{svg}} placeholder.".to_string());
+    }
 
-        if trimmed_line.starts_with('<') && !trimmed_line.starts_with("</") {
-            if !trimmed_line.contains("/>") && !trimmed_line.ends_with("?>") {
-                indent_level += 1;
-            }
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let name = after[..end].trim();
+        if is_placeholder_name(name) && !TEMPLATE_PLACEHOLDERS.contains(&name) {
+            return Err(format!(
+                "Unknown template placeholder {{{{{}}}}}. Available: {}.",
+                name,
+                TEMPLATE_PLACEHOLDERS.join(", ")
+            ));
         }
+        rest = &after[end + 2..];
+    }
+    Ok(())
+}
+
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|ch| ch.is_ascii_alphanumeric())
+}
+
+fn dedent(text: &str) -> String {
+    let indent = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    text.lines()
+        .map(|line| line.get(indent..).unwrap_or(line.trim_start()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn fill_template(template: &str, values: &[(&str, &str)]) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let value = after.find("}}").and_then(|end| {
+            let name = after[..end].trim();
+            values
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| (*value, end))
+        });
+        let Some((value, end)) = value else {
+            output.push_str(&rest[..start + 2]);
+            rest = after;
+            continue;
+        };
+
+        output.push_str(&rest[..start]);
+        let line_start = output.rfind('\n').map(|index| index + 1).unwrap_or(0);
+        let indent = output[line_start..]
+            .chars()
+            .take_while(|ch| ch.is_whitespace())
+            .collect::<String>();
+        output.push_str(&value.replace('\n', &format!("\n{}", indent)));
+        rest = &after[end + 2..];
     }
 
-    indented.join("\n").trim().to_string() + "\n"
+    output.push_str(rest);
+    output
 }
 
 pub fn read_to_string(path: &Path) -> Result<String, String> {
@@ -118,8 +252,21 @@ pub fn read_to_string(path: &Path) -> Result<String, String> {
 }
 
 pub fn write_string(path: &Path, content: &str) -> Result<(), String> {
-    fs::write(path, content)
-        .map_err(|error| format!("Failed to write {}: {}", path.display(), error))
+    let temp_path = temp_path(path);
+    fs::write(&temp_path, content)
+        .and_then(|_| fs::rename(&temp_path, path))
+        .map_err(|error| {
+            let _ = fs::remove_file(&temp_path);
+            format!("Failed to write {}: {}", path.display(), error)
+        })
+}
+
+fn temp_path(path: &Path) -> PathBuf {
+    let filename = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}.cozyutils-tmp", filename))
 }
 
 pub fn ensure_dir(path: &Path) -> Result<(), String> {
@@ -128,13 +275,46 @@ pub fn ensure_dir(path: &Path) -> Result<(), String> {
 }
 
 pub fn move_file(from: &Path, to: &Path) -> Result<(), String> {
-    fs::rename(from, to).map_err(|error| format!("Failed to move {}: {}", from.display(), error))
+    match fs::rename(from, to) {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == io::ErrorKind::CrossesDevices => copy_and_remove(from, to),
+        Err(error) => Err(format!("Failed to move {}: {}", from.display(), error)),
+    }
 }
 
-pub fn join_path(base: &str, file: &str) -> PathBuf {
-    let mut path = PathBuf::from(base);
-    path.push(file);
-    path
+fn copy_and_remove(from: &Path, to: &Path) -> Result<(), String> {
+    fs::copy(from, to).map_err(|error| {
+        let _ = fs::remove_file(to);
+        format!("Failed to copy {}: {}", from.display(), error)
+    })?;
+    fs::remove_file(from).map_err(|error| {
+        let _ = fs::remove_file(to);
+        format!("Failed to move {}: {}", from.display(), error)
+    })
+}
+
+pub fn remove_file(path: &Path) -> Result<(), String> {
+    fs::remove_file(path).map_err(|error| format!("Failed to remove {}: {}", path.display(), error))
+}
+
+pub fn list_subdirectories(path: &Path) -> Vec<String> {
+    let Ok(read_dir) = fs::read_dir(path) else {
+        return Vec::new();
+    };
+
+    let mut entries: Vec<String> = read_dir
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| !name.starts_with('.') && name != "node_modules")
+        .collect();
+    entries.sort();
+    entries
+}
+
+pub fn matches_extension(name: &str, ext_filter: &[String]) -> bool {
+    let normalized = normalize_extensions(ext_filter);
+    normalized.is_empty() || has_matching_extension(name, &normalized)
 }
 
 fn normalize_extensions(ext_filter: &[String]) -> Vec<String> {
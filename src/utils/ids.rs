@@ -0,0 +1,344 @@
+use crate::utils::jsx::{JsxElement, JsxNode, JsxValue, escape_template};
+use crate::utils::svg::{Element, Node};
+
+pub const ID_PREFIX_VARIABLE: &str = "idPrefix";
+const HREF_ATTRIBUTES: &[&str] = &["href", "xlinkHref", "xlink:href"];
+const ID_LIST_ATTRIBUTES: &[&str] = &["aria-labelledby", "aria-describedby"];
+const GROUP_AT_RULES: &[&str] = &["@media", "@supports", "@container", "@layer", "@document"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IdMode {
+    UseId,
+    Prefix,
+    Keep,
+}
+
+impl IdMode {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "use-id" => Ok(IdMode::UseId),
+            "prefix" => Ok(IdMode::Prefix),
+            "keep" => Ok(IdMode::Keep),
+            _ => Err(format!(
+                "Unsupported id mode '{}'. Use 'use-id', 'prefix', or 'keep'.",
+                value
+            )),
+        }
+    }
+}
+
+enum Segment {
+    Literal(String),
+    Id(String),
+}
+
+pub fn collect_ids(root: &Element) -> Vec<String> {
+    let mut ids = Vec::new();
+    root.visit(&mut |element| {
+        for (name, value) in &element.attributes {
+            if name == "id" && !value.is_empty() && !ids.contains(value) {
+                ids.push(value.clone());
+            }
+        }
+    });
+    ids
+}
+
+fn push_literal(segments: &mut Vec<Segment>, text: &str) {
+    if let Some(Segment::Literal(existing)) = segments.last_mut() {
+        existing.push_str(text);
+    } else if !text.is_empty() {
+        segments.push(Segment::Literal(text.to_string()));
+    }
+}
+
+fn split_references(name: &str, value: &str, ids: &[String]) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let is_known = |id: &str| ids.iter().any(|known| known == id);
+
+    if name == "id" && is_known(value) {
+        segments.push(Segment::Id(value.to_string()));
+        return segments;
+    }
+
+    if HREF_ATTRIBUTES.contains(&name)
+        && let Some(id) = value.strip_prefix('#')
+        && is_known(id)
+    {
+        segments.push(Segment::Literal("#".to_string()));
+        segments.push(Segment::Id(id.to_string()));
+        return segments;
+    }
+
+    if ID_LIST_ATTRIBUTES.contains(&name) {
+        for (index, token) in value.split_whitespace().enumerate() {
+            if index > 0 {
+                push_literal(&mut segments, " ");
+            }
+            if is_known(token) {
+                segments.push(Segment::Id(token.to_string()));
+            } else {
+                push_literal(&mut segments, token);
+            }
+        }
+        return segments;
+    }
+
+    let mut rest = value;
+    while let Some(start) = rest.find("url(#") {
+        let after = &rest[start + 5..];
+        let Some(end) = after.find(')') else {
+            break;
+        };
+        let id = after[..end].trim_matches(['"', '\'']);
+        push_literal(&mut segments, &rest[..start + 5]);
+        if is_known(id) {
+            segments.push(Segment::Id(id.to_string()));
+        } else {
+            push_literal(&mut segments, &after[..end]);
+        }
+        rest = &after[end..];
+    }
+    push_literal(&mut segments, rest);
+    segments
+}
+
+fn is_group_rule(prelude: &str) -> bool {
+    GROUP_AT_RULES.iter().any(|rule| prelude.starts_with(rule))
+}
+
+fn split_css_references(css: &str, names: &[String], marker: char) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let is_known = |name: &str| names.iter().any(|known| known == name);
+    let mut blocks: Vec<bool> = Vec::new();
+    let mut prelude_start = 0;
+    let mut literal_start = 0;
+    let mut index = 0;
+
+    while index < css.len() {
+        let rest = &css[index..];
+        if rest.starts_with("/*") {
+            index += rest.find("*/").map(|end| end + 2).unwrap_or(rest.len());
+            continue;
+        }
+        if marker == '#'
+            && rest.starts_with("url(")
+            && let Some(end) = rest.find(')')
+        {
+            let inner = &rest[4..end];
+            if let Some(id) = inner.trim().trim_matches(['"', '\'']).strip_prefix('#')
+                && is_known(id)
+                && let Some(hash) = inner.find('#')
+            {
+                let id_start = index + 4 + hash + 1;
+                push_literal(&mut segments, &css[literal_start..id_start]);
+                segments.push(Segment::Id(id.to_string()));
+                literal_start = id_start + id.len();
+            }
+            index += end + 1;
+            continue;
+        }
+
+        let ch = rest.chars().next().unwrap_or_default();
+        match ch {
+            '{' => {
+                blocks.push(!is_group_rule(css[prelude_start..index].trim()));
+                prelude_start = index + 1;
+            }
+            '}' => {
+                blocks.pop();
+                prelude_start = index + 1;
+            }
+            ';' => prelude_start = index + 1,
+            _ if ch == marker && !blocks.last().copied().unwrap_or(false) => {
+                let name = &rest[1..];
+                let end = name
+                    .find(|ch: char| !(ch.is_alphanumeric() || ch == '-' || ch == '_'))
+                    .unwrap_or(name.len());
+                if is_known(&name[..end]) {
+                    push_literal(&mut segments, &css[literal_start..index + 1]);
+                    segments.push(Segment::Id(name[..end].to_string()));
+                    index += 1 + end;
+                    literal_start = index;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        index += ch.len_utf8();
+    }
+    push_literal(&mut segments, &css[literal_start..]);
+    segments
+}
+
+pub fn styles_reference_ids(root: &Element, ids: &[String]) -> bool {
+    let mut found = false;
+    root.visit(&mut |element| {
+        if element.name == "style" {
+            found |= element.children.iter().any(|child| match child {
+                Node::Text(text) | Node::CData(text) => split_css_references(text, ids, '#')
+                    .iter()
+                    .any(|segment| matches!(segment, Segment::Id(_))),
+                _ => false,
+            });
+        }
+    });
+    found
+}
+
+fn scoped_value(
+    name: &str,
+    value: &str,
+    ids: &[String],
+    mode: IdMode,
+    prefix: &str,
+) -> Option<JsxValue> {
+    scoped_segments(&split_references(name, value, ids), mode, prefix)
+}
+
+fn scoped_segments(segments: &[Segment], mode: IdMode, prefix: &str) -> Option<JsxValue> {
+    if !segments
+        .iter()
+        .any(|segment| matches!(segment, Segment::Id(_)))
+    {
+        return None;
+    }
+
+    match mode {
+        IdMode::Keep => None,
+        IdMode::Prefix => Some(JsxValue::String(
+            segments
+                .iter()
+                .map(|segment| match segment {
+                    Segment::Literal(text) => text.clone(),
+                    Segment::Id(id) => format!("{}-{}", prefix, id),
+                })
+                .collect(),
+        )),
+        IdMode::UseId => Some(JsxValue::Expression(format!(
+            "`{}`",
+            segments
+                .iter()
+                .map(|segment| match segment {
+                    Segment::Literal(text) => escape_template(text),
+                    Segment::Id(id) => format!("${{{}}}-{}", ID_PREFIX_VARIABLE, id),
+                })
+                .collect::<String>()
+        ))),
+    }
+}
+
+pub fn scope_ids(element: &mut JsxElement, ids: &[String], mode: IdMode, prefix: &str) {
+    if ids.is_empty() || mode == IdMode::Keep {
+        return;
+    }
+
+    for (name, value) in element.attributes.iter_mut() {
+        match value {
+            JsxValue::String(text) => {
+                if let Some(scoped) = scoped_value(name, text, ids, mode, prefix) {
+                    *value = scoped;
+                }
+            }
+            JsxValue::Style(entries) => {
+                for (property, entry) in entries.iter_mut() {
+                    if let JsxValue::String(text) = entry
+                        && let Some(scoped) = scoped_value(property, text, ids, mode, prefix)
+                    {
+                        *entry = scoped;
+                    }
+                }
+            }
+            JsxValue::Expression(_) => {}
+        }
+    }
+
+    let is_style = element.name == "style";
+    for child in element.children.iter_mut() {
+        match child {
+            JsxNode::Element(child) => scope_ids(child, ids, mode, prefix),
+            JsxNode::RawText(text) if is_style => {
+                match scoped_segments(&split_css_references(text, ids, '#'), mode, prefix) {
+                    Some(JsxValue::String(scoped)) => *text = scoped,
+                    Some(JsxValue::Expression(expression)) => {
+                        *child = JsxNode::Expression(expression)
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn prefix_ids(element: &mut Element, ids: &[String], prefix: &str) {
+    if ids.is_empty() {
+        return;
+    }
+
+    element.visit_mut(&mut |element| {
+        for (name, value) in element.attributes.iter_mut() {
+            if let Some(JsxValue::String(scoped)) =
+                scoped_value(name, value, ids, IdMode::Prefix, prefix)
+            {
+                *value = scoped;
+            }
+        }
+        if element.name == "style" {
+            for child in element.children.iter_mut() {
+                if let Node::Text(text) | Node::CData(text) = child
+                    && let Some(JsxValue::String(scoped)) = scoped_segments(
+                        &split_css_references(text, ids, '#'),
+                        IdMode::Prefix,
+                        prefix,
+                    )
+                {
+                    *text = scoped;
+                }
+            }
+        }
+    });
+}
+
+pub fn prefix_classes(element: &mut Element, prefix: &str) {
+    let mut classes: Vec<String> = Vec::new();
+    element.visit(&mut |element| {
+        for (name, value) in &element.attributes {
+            if name == "class" {
+                for class in value.split_whitespace() {
+                    if !classes.iter().any(|known| known == class) {
+                        classes.push(class.to_string());
+                    }
+                }
+            }
+        }
+    });
+    if classes.is_empty() {
+        return;
+    }
+
+    element.visit_mut(&mut |element| {
+        for (name, value) in element.attributes.iter_mut() {
+            if name == "class" {
+                *value = value
+                    .split_whitespace()
+                    .map(|class| format!("{}-{}", prefix, class))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+            }
+        }
+        if element.name == "style" {
+            for child in element.children.iter_mut() {
+                if let Node::Text(text) | Node::CData(text) = child
+                    && let Some(JsxValue::String(scoped)) = scoped_segments(
+                        &split_css_references(text, &classes, '.'),
+                        IdMode::Prefix,
+                        prefix,
+                    )
+                {
+                    *text = scoped;
+                }
+            }
+        }
+    });
+}
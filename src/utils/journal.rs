@@ -0,0 +1,528 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::diff::unified_diff;
+use crate::utils::fs::{
+    ensure_dir, move_file, read_dir_and_sort, read_to_string, remove_file, write_string,
+};
+use crate::utils::manifest::content_hash;
+
+pub const JOURNAL_FILENAME: &str = ".cozyutils-journal.json";
+const JOURNAL_VERSION: u32 = 1;
+const STAGED_SUFFIX: &str = ".cozyutils-staged";
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum JournalEntry {
+    Write {
+        path: PathBuf,
+        previous: Option<String>,
+        hash: String,
+    },
+    Remove {
+        path: PathBuf,
+        previous: String,
+    },
+    Move {
+        from: PathBuf,
+        to: PathBuf,
+    },
+    CreateDir {
+        path: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone)]
+enum Staged {
+    Write {
+        path: PathBuf,
+        temp: PathBuf,
+        content: String,
+        previous: Option<String>,
+    },
+    Remove {
+        path: PathBuf,
+    },
+    Move {
+        from: PathBuf,
+        to: PathBuf,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Journal {
+    pub version: u32,
+    pub entries: Vec<JournalEntry>,
+    #[serde(skip)]
+    staged: Vec<Staged>,
+    #[serde(skip)]
+    preview: Option<BTreeMap<PathBuf, Option<String>>>,
+}
+
+impl Default for Journal {
+    fn default() -> Self {
+        Journal {
+            version: JOURNAL_VERSION,
+            entries: Vec::new(),
+            staged: Vec::new(),
+            preview: None,
+        }
+    }
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn staged_path(path: &Path) -> PathBuf {
+    let filename = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}{}", filename, STAGED_SUFFIX))
+}
+
+impl Journal {
+    pub fn preview() -> Self {
+        Journal {
+            preview: Some(BTreeMap::new()),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_preview(&self) -> bool {
+        self.preview.is_some()
+    }
+
+    pub fn has_changes(&self) -> bool {
+        !self.entries.is_empty() || !self.staged.is_empty()
+    }
+
+    fn pending(&self) -> Vec<(PathBuf, Option<&str>)> {
+        if let Some(planned) = &self.preview {
+            return planned
+                .iter()
+                .map(|(path, content)| (path.clone(), content.as_deref()))
+                .collect();
+        }
+        self.staged
+            .iter()
+            .filter_map(|staged| match staged {
+                Staged::Write { path, content, .. } => Some((path.clone(), Some(content.as_str()))),
+                Staged::Remove { path } => Some((path.clone(), None)),
+                Staged::Move { .. } => None,
+            })
+            .collect()
+    }
+
+    fn planned_content(&self, path: &Path) -> Option<String> {
+        let path = absolute(path);
+        match self
+            .pending()
+            .into_iter()
+            .rev()
+            .find(|(pending, _)| *pending == path)
+        {
+            Some((_, planned)) => planned.map(str::to_string),
+            None => read_to_string(&path).ok(),
+        }
+    }
+
+    pub fn planned_changes(&self) -> usize {
+        let moves = self
+            .staged
+            .iter()
+            .filter(|staged| matches!(staged, Staged::Move { .. }))
+            .count();
+        self.preview.as_ref().map_or(0, |planned| planned.len()) + moves
+    }
+
+    pub fn list_files(&self, directory: &Path, extensions: &[String]) -> Vec<String> {
+        let mut files = if directory.is_dir() {
+            read_dir_and_sort(&directory.to_string_lossy(), extensions)
+        } else {
+            Vec::new()
+        };
+        let directory = absolute(directory);
+        for (path, content) in self.pending() {
+            let (Some(parent), Some(filename)) = (path.parent(), path.file_name()) else {
+                continue;
+            };
+            let filename = filename.to_string_lossy().to_string();
+            if parent != directory
+                || !extensions
+                    .iter()
+                    .any(|extension| filename.ends_with(extension.as_str()))
+            {
+                continue;
+            }
+            match content {
+                Some(_) if !files.contains(&filename) => files.push(filename),
+                None => files.retain(|existing| *existing != filename),
+                _ => {}
+            }
+        }
+        files.sort();
+        files
+    }
+
+    pub fn load(directory: &Path) -> Result<Option<Self>, String> {
+        let path = directory.join(JOURNAL_FILENAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = read_to_string(&path)?;
+        let journal: Journal = serde_json::from_str(&content)
+            .map_err(|error| format!("Failed to parse journal {}: {}", path.display(), error))?;
+        if journal.version != JOURNAL_VERSION {
+            return Err(format!(
+                "Unsupported journal version {} in {}.",
+                journal.version,
+                path.display()
+            ));
+        }
+        Ok(Some(journal))
+    }
+
+    pub fn save(&self, directory: &Path) -> Result<(), String> {
+        if self.entries.is_empty() {
+            return Ok(());
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|error| format!("Failed to serialize journal: {}", error))?;
+        write_string(&directory.join(JOURNAL_FILENAME), &(content + "\n"))
+    }
+
+    pub fn ensure_dir(&mut self, path: &Path) -> Result<(), String> {
+        if self.is_preview() {
+            return Ok(());
+        }
+        let mut missing = Vec::new();
+        let mut current = Some(absolute(path));
+        while let Some(directory) = current {
+            if directory.exists() {
+                break;
+            }
+            current = directory.parent().map(Path::to_path_buf);
+            missing.push(directory);
+        }
+        ensure_dir(path)?;
+        self.entries.extend(
+            missing
+                .into_iter()
+                .rev()
+                .map(|path| JournalEntry::CreateDir { path }),
+        );
+        Ok(())
+    }
+
+    pub fn write_string(&mut self, path: &Path, content: &str) -> Result<(), String> {
+        let previous = self.planned_content(path);
+        if previous.as_deref() == Some(content) {
+            return Ok(());
+        }
+        if let Some(planned) = &mut self.preview {
+            let label = path.display().to_string();
+            match &previous {
+                Some(previous) => {
+                    println!("Would update {}", label);
+                    print!("{}", unified_diff(previous, content, &label, &label));
+                }
+                None => {
+                    println!("Would create {}", label);
+                    print!("{}", unified_diff("", content, "/dev/null", &label));
+                }
+            }
+            planned.insert(absolute(path), Some(content.to_string()));
+            return Ok(());
+        }
+        let path = absolute(path);
+        let temp = staged_path(&path);
+        write_string(&temp, content)?;
+        if let Some(Staged::Write {
+            content: staged, ..
+        }) = self.staged.iter_mut().rev().find(
+            |staged| matches!(staged, Staged::Write { path: existing, .. } if *existing == path),
+        ) {
+            *staged = content.to_string();
+            return Ok(());
+        }
+        let previous = if path.exists() {
+            Some(read_to_string(&path)?)
+        } else {
+            None
+        };
+        self.staged.push(Staged::Write {
+            path,
+            temp,
+            content: content.to_string(),
+            previous,
+        });
+        Ok(())
+    }
+
+    pub fn remove_file(&mut self, path: &Path) -> Result<(), String> {
+        if let Some(planned) = &mut self.preview {
+            println!("Would delete {}", path.display());
+            planned.insert(absolute(path), None);
+            return Ok(());
+        }
+        self.staged.push(Staged::Remove {
+            path: absolute(path),
+        });
+        Ok(())
+    }
+
+    pub fn move_file(&mut self, from: &Path, to: &Path) -> Result<(), String> {
+        if self.is_preview() {
+            println!("Would move {} to {}", from.display(), to.display());
+            return Ok(());
+        }
+        if to.exists() {
+            let previous = read_to_string(to)?;
+            self.entries.push(JournalEntry::Remove {
+                path: absolute(to),
+                previous,
+            });
+        }
+        if let Err(error) = move_file(from, to) {
+            if matches!(self.entries.last(), Some(JournalEntry::Remove { path, .. }) if *path == absolute(to))
+            {
+                self.entries.pop();
+            }
+            return Err(error);
+        }
+        self.entries.push(JournalEntry::Move {
+            from: absolute(from),
+            to: absolute(to),
+        });
+        Ok(())
+    }
+
+    pub fn stage_move(&mut self, from: &Path, to: &Path) {
+        self.staged.push(Staged::Move {
+            from: from.to_path_buf(),
+            to: to.to_path_buf(),
+        });
+    }
+
+    pub fn commit(&mut self) -> Result<(), String> {
+        let staged = std::mem::take(&mut self.staged);
+        for (index, operation) in staged.iter().enumerate() {
+            if let Err(error) = self.commit_operation(operation) {
+                self.staged = staged[index..].to_vec();
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    fn commit_operation(&mut self, operation: &Staged) -> Result<(), String> {
+        match operation {
+            Staged::Write {
+                path,
+                temp,
+                content,
+                previous,
+            } => {
+                fs::rename(temp, path)
+                    .map_err(|error| format!("Failed to write {}: {}", path.display(), error))?;
+                self.entries.push(JournalEntry::Write {
+                    path: path.clone(),
+                    previous: previous.clone(),
+                    hash: content_hash(content),
+                });
+            }
+            Staged::Remove { path } => {
+                if !path.exists() {
+                    return Ok(());
+                }
+                let previous = read_to_string(path)?;
+                remove_file(path)?;
+                self.entries.push(JournalEntry::Remove {
+                    path: path.clone(),
+                    previous,
+                });
+            }
+            Staged::Move { from, to } => {
+                if let Some(parent) = to.parent() {
+                    self.ensure_dir(parent)?;
+                }
+                self.move_file(from, to)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn rollback(&mut self) -> Result<(), String> {
+        for staged in std::mem::take(&mut self.staged) {
+            if let Staged::Write { temp, .. } = staged
+                && temp.exists()
+            {
+                remove_file(&temp)?;
+            }
+        }
+        self.undo(true)
+    }
+
+    pub fn undo(&mut self, force: bool) -> Result<(), String> {
+        while let Some(entry) = self.entries.last().cloned() {
+            undo_entry(entry, force)?;
+            self.entries.pop();
+        }
+        Ok(())
+    }
+}
+
+fn undo_entry(entry: JournalEntry, force: bool) -> Result<(), String> {
+    match entry {
+        JournalEntry::Write {
+            path,
+            previous,
+            hash,
+        } => {
+            let current = read_to_string(&path).ok().map(|text| content_hash(&text));
+            if current.is_some_and(|current| current != hash) && !force {
+                println!(
+                    "Kept {}: it changed after the run (use --force to revert anyway).",
+                    path.display()
+                );
+                return Ok(());
+            }
+            match previous {
+                Some(previous) => {
+                    write_string(&path, &previous)?;
+                    println!("Restored {}", path.display());
+                }
+                None if path.exists() => {
+                    remove_file(&path)?;
+                    println!("Deleted {}", path.display());
+                }
+                None => {}
+            }
+        }
+        JournalEntry::Remove { path, previous } => {
+            if let Some(parent) = path.parent() {
+                ensure_dir(parent)?;
+            }
+            write_string(&path, &previous)?;
+            println!("Restored {}", path.display());
+        }
+        JournalEntry::Move { from, to } => {
+            if from.exists() && !force {
+                println!(
+                    "Kept {}: {} already exists (use --force to overwrite).",
+                    to.display(),
+                    from.display()
+                );
+                return Ok(());
+            }
+            if let Some(parent) = from.parent() {
+                ensure_dir(parent)?;
+            }
+            move_file(&to, &from)?;
+            println!("Moved {} back to {}", to.display(), from.display());
+        }
+        JournalEntry::CreateDir { path } => {
+            if fs::remove_dir(&path).is_ok() {
+                println!("Removed directory {}", path.display());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("cozyutils-journal-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        directory
+    }
+
+    fn read(path: &Path) -> String {
+        fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn undo_restores_an_overwritten_barrel() {
+        let directory = temp_dir("undo-barrel");
+        let barrel = directory.join("index.ts");
+        fs::write(&barrel, "export { default as Old } from \"./Old\";\n").unwrap();
+
+        let mut journal = Journal::default();
+        journal
+            .write_string(&barrel, "export { default as New } from \"./New\";\n")
+            .unwrap();
+        journal.commit().unwrap();
+        journal.save(&directory).unwrap();
+        assert_eq!(read(&barrel), "export { default as New } from \"./New\";\n");
+
+        let mut journal = Journal::load(&directory).unwrap().unwrap();
+        journal.undo(false).unwrap();
+        assert_eq!(read(&barrel), "export { default as Old } from \"./Old\";\n");
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn undo_keeps_files_edited_after_the_run_unless_forced() {
+        let directory = temp_dir("undo-edited");
+        let component = directory.join("Arrow.tsx");
+
+        let mut journal = Journal::default();
+        journal.write_string(&component, "generated").unwrap();
+        journal.commit().unwrap();
+        fs::write(&component, "edited by hand").unwrap();
+
+        journal.clone().undo(false).unwrap();
+        assert_eq!(read(&component), "edited by hand");
+
+        journal.undo(true).unwrap();
+        assert!(!component.exists());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn failed_commit_rolls_back_earlier_steps() {
+        let directory = temp_dir("failed-commit");
+        let barrel = directory.join("index.ts");
+        let source = directory.join("arrow.svg");
+        fs::write(&barrel, "previous").unwrap();
+        fs::write(&source, "<svg/>").unwrap();
+
+        let mut journal = Journal::default();
+        journal
+            .write_string(&directory.join("Arrow.tsx"), "component")
+            .unwrap();
+        journal.write_string(&barrel, "updated").unwrap();
+        journal.stage_move(&source, &directory.join("original").join("arrow.svg"));
+        journal.stage_move(
+            &directory.join("missing.svg"),
+            &directory.join("original").join("missing.svg"),
+        );
+        journal
+            .write_string(&directory.join("Later.tsx"), "later")
+            .unwrap();
+
+        assert!(journal.commit().is_err());
+        journal.rollback().unwrap();
+
+        let mut files = fs::read_dir(&directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+        files.sort();
+        assert_eq!(files, ["arrow.svg", "index.ts"]);
+        assert_eq!(read(&barrel), "previous");
+        assert_eq!(read(&source), "<svg/>");
+        assert!(!journal.has_changes());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
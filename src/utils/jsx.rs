@@ -0,0 +1,431 @@
+use crate::utils::svg::{Element, Node, parse_style};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsxValue {
+    String(String),
+    Expression(String),
+    Style(Vec<(String, JsxValue)>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsxNode {
+    Element(JsxElement),
+    Text(String),
+    RawText(String),
+    Expression(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Markup {
+    React,
+    Solid,
+    Vue,
+    Svelte,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JsxElement {
+    pub name: String,
+    pub attributes: Vec<(String, JsxValue)>,
+    pub spreads: Vec<String>,
+    pub children: Vec<JsxNode>,
+}
+
+impl JsxElement {
+    pub fn set_attribute(&mut self, name: &str, value: JsxValue) {
+        match self.attributes.iter_mut().find(|(key, _)| key == name) {
+            Some((_, existing)) => *existing = value,
+            None => self.attributes.push((name.to_string(), value)),
+        }
+    }
+}
+
+pub const SVG_ATTRIBUTE_NAMES: &[(&str, &str)] = &[
+    ("class", "className"),
+    ("crossorigin", "crossOrigin"),
+    ("tabindex", "tabIndex"),
+    ("accent-height", "accentHeight"),
+    ("alignment-baseline", "alignmentBaseline"),
+    ("arabic-form", "arabicForm"),
+    ("baseline-shift", "baselineShift"),
+    ("cap-height", "capHeight"),
+    ("clip-path", "clipPath"),
+    ("clip-rule", "clipRule"),
+    ("color-interpolation", "colorInterpolation"),
+    ("color-interpolation-filters", "colorInterpolationFilters"),
+    ("color-profile", "colorProfile"),
+    ("color-rendering", "colorRendering"),
+    ("dominant-baseline", "dominantBaseline"),
+    ("enable-background", "enableBackground"),
+    ("fill-opacity", "fillOpacity"),
+    ("fill-rule", "fillRule"),
+    ("flood-color", "floodColor"),
+    ("flood-opacity", "floodOpacity"),
+    ("font-family", "fontFamily"),
+    ("font-size", "fontSize"),
+    ("font-size-adjust", "fontSizeAdjust"),
+    ("font-stretch", "fontStretch"),
+    ("font-style", "fontStyle"),
+    ("font-variant", "fontVariant"),
+    ("font-weight", "fontWeight"),
+    ("glyph-name", "glyphName"),
+    ("glyph-orientation-horizontal", "glyphOrientationHorizontal"),
+    ("glyph-orientation-vertical", "glyphOrientationVertical"),
+    ("horiz-adv-x", "horizAdvX"),
+    ("horiz-origin-x", "horizOriginX"),
+    ("image-rendering", "imageRendering"),
+    ("letter-spacing", "letterSpacing"),
+    ("lighting-color", "lightingColor"),
+    ("marker-end", "markerEnd"),
+    ("marker-mid", "markerMid"),
+    ("marker-start", "markerStart"),
+    ("overline-position", "overlinePosition"),
+    ("overline-thickness", "overlineThickness"),
+    ("paint-order", "paintOrder"),
+    ("panose-1", "panose1"),
+    ("pointer-events", "pointerEvents"),
+    ("rendering-intent", "renderingIntent"),
+    ("shape-rendering", "shapeRendering"),
+    ("stop-color", "stopColor"),
+    ("stop-opacity", "stopOpacity"),
+    ("strikethrough-position", "strikethroughPosition"),
+    ("strikethrough-thickness", "strikethroughThickness"),
+    ("stroke-dasharray", "strokeDasharray"),
+    ("stroke-dashoffset", "strokeDashoffset"),
+    ("stroke-linecap", "strokeLinecap"),
+    ("stroke-linejoin", "strokeLinejoin"),
+    ("stroke-miterlimit", "strokeMiterlimit"),
+    ("stroke-opacity", "strokeOpacity"),
+    ("stroke-width", "strokeWidth"),
+    ("text-anchor", "textAnchor"),
+    ("text-decoration", "textDecoration"),
+    ("text-rendering", "textRendering"),
+    ("transform-origin", "transformOrigin"),
+    ("underline-position", "underlinePosition"),
+    ("underline-thickness", "underlineThickness"),
+    ("unicode-bidi", "unicodeBidi"),
+    ("unicode-range", "unicodeRange"),
+    ("units-per-em", "unitsPerEm"),
+    ("v-alphabetic", "vAlphabetic"),
+    ("v-hanging", "vHanging"),
+    ("v-ideographic", "vIdeographic"),
+    ("v-mathematical", "vMathematical"),
+    ("vector-effect", "vectorEffect"),
+    ("vert-adv-y", "vertAdvY"),
+    ("vert-origin-x", "vertOriginX"),
+    ("vert-origin-y", "vertOriginY"),
+    ("word-spacing", "wordSpacing"),
+    ("writing-mode", "writingMode"),
+    ("x-height", "xHeight"),
+    ("xlink:actuate", "xlinkActuate"),
+    ("xlink:arcrole", "xlinkArcrole"),
+    ("xlink:href", "xlinkHref"),
+    ("xlink:role", "xlinkRole"),
+    ("xlink:show", "xlinkShow"),
+    ("xlink:title", "xlinkTitle"),
+    ("xlink:type", "xlinkType"),
+    ("xml:base", "xmlBase"),
+    ("xml:lang", "xmlLang"),
+    ("xml:space", "xmlSpace"),
+    ("xmlns:xlink", "xmlnsXlink"),
+];
+
+pub fn jsx_attribute_name(name: &str) -> String {
+    SVG_ATTRIBUTE_NAMES
+        .iter()
+        .find(|(svg_name, _)| *svg_name == name)
+        .map(|(_, jsx_name)| jsx_name.to_string())
+        .unwrap_or_else(|| name.to_string())
+}
+
+pub fn remove_namespaced_attributes(element: &mut JsxElement) {
+    element.attributes.retain(|(name, _)| !name.contains(':'));
+    for child in element.children.iter_mut() {
+        if let JsxNode::Element(child) = child {
+            remove_namespaced_attributes(child);
+        }
+    }
+}
+
+pub fn svg_attribute_name(name: &str) -> String {
+    SVG_ATTRIBUTE_NAMES
+        .iter()
+        .find(|(_, jsx_name)| *jsx_name == name)
+        .map(|(svg_name, _)| svg_name.to_string())
+        .unwrap_or_else(|| name.to_string())
+}
+
+const UNITLESS_STYLE_PROPERTIES: &[&str] = &[
+    "fill-opacity",
+    "flex",
+    "flood-opacity",
+    "font-weight",
+    "line-height",
+    "opacity",
+    "stop-opacity",
+    "stroke-dashoffset",
+    "stroke-miterlimit",
+    "stroke-opacity",
+    "stroke-width",
+    "z-index",
+];
+
+pub fn style_property_name(property: &str) -> String {
+    if property.starts_with("--") {
+        return json_string(property);
+    }
+
+    let property = match property.strip_prefix("-ms-") {
+        Some(rest) => format!("ms-{}", rest),
+        None => property.to_string(),
+    };
+    let mut sections = property.split('-');
+    let mut converted = sections.next().unwrap_or("").to_string();
+    for section in sections {
+        let mut chars = section.chars();
+        if let Some(first) = chars.next() {
+            converted.extend(first.to_uppercase());
+            converted.push_str(chars.as_str());
+        }
+    }
+    converted
+}
+
+fn render_style(entries: &[(String, JsxValue)], markup: Markup) -> String {
+    let entries = entries
+        .iter()
+        .map(|(property, value)| {
+            let value = match value {
+                JsxValue::String(text)
+                    if markup == Markup::React
+                        && UNITLESS_STYLE_PROPERTIES.contains(&property.as_str())
+                        && text.parse::<f64>().is_ok_and(f64::is_finite) =>
+                {
+                    text.clone()
+                }
+                JsxValue::String(text) if markup == Markup::Vue => js_single_quoted(text),
+                JsxValue::String(text) => json_string(text),
+                JsxValue::Expression(expression) => expression.clone(),
+                JsxValue::Style(nested) => render_style(nested, markup),
+            };
+            let key = match markup {
+                Markup::React => style_property_name(property),
+                Markup::Vue if is_identifier(property) => property.clone(),
+                Markup::Vue => js_single_quoted(property),
+                _ if is_identifier(property) => property.clone(),
+                _ => json_string(property),
+            };
+            format!("{}: {}", key, value)
+        })
+        .collect::<Vec<_>>();
+
+    if entries.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{ {} }}", entries.join(", "))
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '$')
+        && !name.starts_with(|ch: char| ch.is_ascii_digit())
+}
+
+pub fn element_to_jsx(element: &Element) -> JsxElement {
+    let attributes = element
+        .attributes
+        .iter()
+        .map(|(name, value)| match name.as_str() {
+            "style" => {
+                let entries = parse_style(value)
+                    .into_iter()
+                    .map(|(property, value)| (property, JsxValue::String(value)))
+                    .collect();
+                (name.clone(), JsxValue::Style(entries))
+            }
+            _ => (jsx_attribute_name(name), JsxValue::String(value.clone())),
+        })
+        .collect();
+
+    let raw_text = element.name == "style" || element.name == "script";
+    let children = element
+        .children
+        .iter()
+        .filter_map(|child| match child {
+            Node::Element(child) => Some(JsxNode::Element(element_to_jsx(child))),
+            Node::Text(text) | Node::CData(text) if raw_text => {
+                Some(JsxNode::RawText(text.trim().to_string()))
+            }
+            Node::Text(text) | Node::CData(text) => {
+                let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                (!collapsed.is_empty()).then_some(JsxNode::Text(collapsed))
+            }
+            _ => None,
+        })
+        .collect();
+
+    JsxElement {
+        name: element.name.clone(),
+        attributes,
+        spreads: Vec::new(),
+        children,
+    }
+}
+
+pub fn render_markup(element: &JsxElement, depth: usize, markup: Markup) -> String {
+    let indent = "  ".repeat(depth);
+    let (tag, close) = if markup == Markup::Vue && element.name == "style" {
+        ("component is=\"style\"", "component")
+    } else {
+        (element.name.as_str(), element.name.as_str())
+    };
+    let mut open = format!("{}<{}", indent, tag);
+    for (name, value) in &element.attributes {
+        let name = match markup {
+            Markup::React => name.clone(),
+            _ => svg_attribute_name(name),
+        };
+        open.push(' ');
+        open.push_str(&render_attribute(&name, value, markup));
+    }
+    if markup != Markup::Vue {
+        for spread in &element.spreads {
+            open.push_str(&format!(" {{...{}}}", spread));
+        }
+    }
+
+    if element.children.is_empty() {
+        return format!("{} />", open);
+    }
+
+    let inline = element
+        .children
+        .iter()
+        .all(|child| !matches!(child, JsxNode::Element(_)));
+    if inline {
+        let children = element
+            .children
+            .iter()
+            .map(|child| render_child(child, 0, markup))
+            .collect::<String>();
+        return format!("{}>{}</{}>", open, children, close);
+    }
+
+    let children = element
+        .children
+        .iter()
+        .map(|child| render_child(child, depth + 1, markup))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("{}>\n{}\n{}</{}>", open, children, indent, close)
+}
+
+fn render_child(child: &JsxNode, depth: usize, markup: Markup) -> String {
+    let indent = "  ".repeat(depth);
+    match (child, markup) {
+        (JsxNode::Element(element), _) => render_markup(element, depth, markup),
+        (JsxNode::Text(text), Markup::Vue) => format!("{}{}", indent, escape_html(text)),
+        (JsxNode::Text(text), Markup::Svelte) if text.contains(['{', '}', '<', '&']) => {
+            format!("{}{{{}}}", indent, json_string(text))
+        }
+        (JsxNode::Text(text), Markup::Svelte) => format!("{}{}", indent, text),
+        (JsxNode::Text(text), _) => format!("{}{}", indent, escape_text(text)),
+        (JsxNode::RawText(text), Markup::Vue | Markup::Svelte) => format!("{}{}", indent, text),
+        (JsxNode::RawText(text), _) => format!("{}{{{}}}", indent, template_literal(text)),
+        (JsxNode::Expression(expression), _) => format!("{}{{{}}}", indent, expression),
+    }
+}
+
+fn render_attribute(name: &str, value: &JsxValue, markup: Markup) -> String {
+    match (value, markup) {
+        (JsxValue::String(text), Markup::Vue) => format!("{}=\"{}\"", name, escape_html(text)),
+        (JsxValue::String(text), Markup::Svelte) if text.contains(['"', '&', '\n', '{', '}']) => {
+            format!("{}={{{}}}", name, json_string(text))
+        }
+        (JsxValue::String(text), _) if text.contains(['"', '&', '\n']) => {
+            format!("{}={{{}}}", name, json_string(text))
+        }
+        (JsxValue::String(text), _) => format!("{}=\"{}\"", name, text),
+        (JsxValue::Expression(expression), Markup::Vue) => {
+            format!(":{}=\"{}\"", name, escape_html(expression))
+        }
+        (JsxValue::Expression(expression), _) => format!("{}={{{}}}", name, expression),
+        (JsxValue::Style(entries), Markup::Vue) => format!(
+            ":{}=\"{}\"",
+            name,
+            escape_html(&render_style(entries, markup))
+        ),
+        (JsxValue::Style(entries), Markup::Svelte) => entries
+            .iter()
+            .map(|(property, value)| match value {
+                JsxValue::String(text) if !text.contains(['"', '{', '}']) => {
+                    format!("style:{}=\"{}\"", property, text)
+                }
+                JsxValue::String(text) => format!("style:{}={{{}}}", property, json_string(text)),
+                JsxValue::Expression(expression) => {
+                    format!("style:{}={{{}}}", property, expression)
+                }
+                JsxValue::Style(nested) => {
+                    format!("style:{}={{{}}}", property, render_style(nested, markup))
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" "),
+        (JsxValue::Style(entries), _) => {
+            format!("{}={{{}}}", name, render_style(entries, markup))
+        }
+    }
+}
+
+fn escape_text(text: &str) -> String {
+    if text.contains(['{', '}', '<', '>', '&']) {
+        format!("{{{}}}", json_string(text))
+    } else {
+        text.to_string()
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn js_single_quoted(text: &str) -> String {
+    format!("'{}'", text.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+pub fn json_string(text: &str) -> String {
+    serde_json::to_string(text).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+pub fn escape_template(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace("${", "\\${")
+}
+
+fn template_literal(text: &str) -> String {
+    format!("`{}`", escape_template(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn svg_attribute_names_round_trip() {
+        for (svg_name, jsx_name) in SVG_ATTRIBUTE_NAMES {
+            assert_eq!(jsx_attribute_name(svg_name), *jsx_name);
+            assert_eq!(svg_attribute_name(jsx_name), *svg_name);
+        }
+    }
+
+    #[test]
+    fn unknown_attribute_names_pass_through() {
+        assert_eq!(jsx_attribute_name("data-name"), "data-name");
+        assert_eq!(svg_attribute_name("aria-label"), "aria-label");
+    }
+}
@@ -0,0 +1,294 @@
+use std::collections::BTreeMap;
+
+use crate::utils::dimensions::view_box;
+use crate::utils::svg::{is_event_handler, is_javascript_link, is_script_element, parse_svg};
+
+const RULES: &[(&str, Severity)] = &[
+    ("parse-error", Severity::Error),
+    ("missing-view-box", Severity::Error),
+    ("script", Severity::Error),
+    ("raster-image", Severity::Warning),
+    ("hardcoded-size", Severity::Warning),
+    ("non-square-view-box", Severity::Warning),
+];
+const RELATIVE_UNITS: &[&str] = &["em", "rem", "%"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Off,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim() {
+            "off" => Ok(Severity::Off),
+            "warn" | "warning" => Ok(Severity::Warning),
+            "error" => Ok(Severity::Error),
+            _ => Err(format!(
+                "Unsupported severity '{}'. Use 'off', 'warning', or 'error'.",
+                value
+            )),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Severity::Off => "off",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LintRules {
+    severities: BTreeMap<&'static str, Severity>,
+}
+
+impl Default for LintRules {
+    fn default() -> Self {
+        LintRules {
+            severities: RULES.iter().cloned().collect(),
+        }
+    }
+}
+
+impl LintRules {
+    pub fn parse(value: Option<&str>) -> Result<Self, String> {
+        let mut rules = LintRules::default();
+        let Some(value) = value else {
+            return Ok(rules);
+        };
+        for entry in value.split(',').filter(|entry| !entry.trim().is_empty()) {
+            let Some((name, severity)) = entry.split_once(':') else {
+                return Err(format!(
+                    "Invalid rule '{}'. Expected name:off|warning|error.",
+                    entry
+                ));
+            };
+            let Some((rule, _)) = RULES.iter().find(|(rule, _)| *rule == name.trim()) else {
+                return Err(format!(
+                    "Unknown lint rule '{}'. Available rules: {}.",
+                    name.trim(),
+                    RULES
+                        .iter()
+                        .map(|(rule, _)| *rule)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            };
+            rules.severities.insert(rule, Severity::parse(severity)?);
+        }
+        Ok(rules)
+    }
+
+    fn severity(&self, rule: &str) -> Severity {
+        self.severities.get(rule).copied().unwrap_or(Severity::Off)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub rule: &'static str,
+    pub severity: Severity,
+    pub message: String,
+}
+
+struct Linter<'a> {
+    rules: &'a LintRules,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Linter<'_> {
+    fn report(&mut self, rule: &'static str, message: String) {
+        let severity = self.rules.severity(rule);
+        if severity != Severity::Off {
+            self.diagnostics.push(Diagnostic {
+                rule,
+                severity,
+                message,
+            });
+        }
+    }
+
+    fn warn(&mut self, rule: &'static str, message: String) {
+        let severity = match self.rules.severity(rule) {
+            Severity::Error => Severity::Warning,
+            severity => severity,
+        };
+        if severity != Severity::Off {
+            self.diagnostics.push(Diagnostic {
+                rule,
+                severity,
+                message,
+            });
+        }
+    }
+}
+
+fn parse_view_box(value: &str) -> Option<(f64, f64)> {
+    let numbers = value
+        .split(|character: char| character.is_whitespace() || character == ',')
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse::<f64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    match numbers.as_slice() {
+        [_, _, width, height] if *width > 0.0 && *height > 0.0 => Some((*width, *height)),
+        _ => None,
+    }
+}
+
+pub fn lint_svg(content: &str, rules: &LintRules) -> Vec<Diagnostic> {
+    let mut linter = Linter {
+        rules,
+        diagnostics: Vec::new(),
+    };
+    let document = match parse_svg(content) {
+        Ok(document) => document,
+        Err(error) => {
+            linter.report("parse-error", error);
+            return linter.diagnostics;
+        }
+    };
+    let Some(root) = document.root() else {
+        return linter.diagnostics;
+    };
+
+    match root.attribute("viewBox") {
+        None if view_box(root).is_some() => linter.warn(
+            "missing-view-box",
+            "<svg> has no viewBox; one will be derived from its width and height.".to_string(),
+        ),
+        None => linter.report(
+            "missing-view-box",
+            "<svg> has no viewBox, so the icon cannot scale.".to_string(),
+        ),
+        Some(value) => match parse_view_box(value) {
+            None => linter.report(
+                "missing-view-box",
+                format!(
+                    "viewBox '{}' is not four numbers with a positive size.",
+                    value
+                ),
+            ),
+            Some((width, height)) if width != height => linter.report(
+                "non-square-view-box",
+                format!(
+                    "viewBox '{}' is not square ({} x {}).",
+                    value, width, height
+                ),
+            ),
+            Some(_) => {}
+        },
+    }
+
+    for name in ["width", "height"] {
+        if let Some(value) = root.attribute(name)
+            && !RELATIVE_UNITS
+                .iter()
+                .any(|unit| value.trim().ends_with(unit))
+        {
+            linter.report(
+                "hardcoded-size",
+                format!("<svg> has a hardcoded {}=\"{}\".", name, value),
+            );
+        }
+    }
+
+    root.visit(&mut |element| {
+        if element.name == "image" {
+            let embedded = ["href", "xlink:href"]
+                .iter()
+                .filter_map(|name| element.attribute(name))
+                .any(|href| href.starts_with("data:"));
+            linter.report(
+                "raster-image",
+                if embedded {
+                    "<image> embeds a raster image as a data URI.".to_string()
+                } else {
+                    "<image> references a raster image.".to_string()
+                },
+            );
+        }
+        if is_script_element(element) {
+            linter.report("script", "<script> elements are not allowed.".to_string());
+        }
+        for (name, value) in &element.attributes {
+            if is_event_handler(name) {
+                linter.report(
+                    "script",
+                    format!(
+                        "<{}> has an event handler attribute {}.",
+                        element.name, name
+                    ),
+                );
+            } else if is_javascript_link(name, value) {
+                linter.report(
+                    "script",
+                    format!("<{}> has a javascript: link.", element.name),
+                );
+            }
+        }
+    });
+    linter.diagnostics
+}
+
+pub fn print_diagnostics(label: &str, diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        println!(
+            "{}: {} [{}] {}",
+            label,
+            diagnostic.severity.name(),
+            diagnostic.rule,
+            diagnostic.message
+        );
+    }
+}
+
+pub fn count_severity(diagnostics: &[Diagnostic], severity: Severity) -> usize {
+    diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == severity)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_scripts_regardless_of_case() {
+        let diagnostics = lint_svg(
+            "<svg viewBox=\"0 0 24 24\" ONLOAD=\"alert(1)\"><SCRIPT>alert(2)</SCRIPT><a HREF=\"JavaScript:alert(3)\"/></svg>",
+            &LintRules::default(),
+        );
+        let scripts = diagnostics
+            .iter()
+            .filter(|diagnostic| {
+                diagnostic.rule == "script" && diagnostic.severity == Severity::Error
+            })
+            .count();
+        assert_eq!(scripts, 3, "{:?}", diagnostics);
+    }
+
+    #[test]
+    fn warns_when_a_view_box_can_be_derived() {
+        let rules = LintRules::default();
+        let derived = lint_svg("<svg width=\"24\" height=\"24\"></svg>", &rules);
+        assert!(
+            derived
+                .iter()
+                .any(|diagnostic| diagnostic.rule == "missing-view-box"
+                    && diagnostic.severity == Severity::Warning)
+        );
+        let missing = lint_svg("<svg></svg>", &rules);
+        assert!(
+            missing
+                .iter()
+                .any(|diagnostic| diagnostic.rule == "missing-view-box"
+                    && diagnostic.severity == Severity::Error)
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::fs::read_to_string;
+use crate::utils::journal::Journal;
+
+pub const MANIFEST_FILENAME: &str = ".cozyutils-icons.json";
+const MANIFEST_VERSION: u32 = 1;
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct ManifestEntry {
+    pub source_hash: String,
+    pub options_hash: String,
+    pub outputs: BTreeMap<String, String>,
+}
+
+impl ManifestEntry {
+    pub fn is_current(
+        &self,
+        source_hash: &str,
+        options_hash: &str,
+        outputs: &[(String, PathBuf)],
+    ) -> bool {
+        self.source_hash == source_hash
+            && self.options_hash == options_hash
+            && self.outputs.len() == outputs.len()
+            && outputs.iter().all(|(key, path)| {
+                self.outputs.get(key).is_some_and(|recorded| {
+                    file_hash(path).is_some_and(|current| current == *recorded)
+                })
+            })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Manifest {
+    pub version: u32,
+    pub icons: BTreeMap<String, ManifestEntry>,
+}
+
+impl Default for Manifest {
+    fn default() -> Self {
+        Manifest {
+            version: MANIFEST_VERSION,
+            icons: BTreeMap::new(),
+        }
+    }
+}
+
+impl Manifest {
+    pub fn load(directory: &Path) -> Result<Self, String> {
+        let path = directory.join(MANIFEST_FILENAME);
+        if !path.exists() {
+            return Ok(Manifest::default());
+        }
+        let content = read_to_string(&path)?;
+        let manifest: Manifest = serde_json::from_str(&content)
+            .map_err(|error| format!("Failed to parse manifest {}: {}", path.display(), error))?;
+        if manifest.version != MANIFEST_VERSION {
+            return Ok(Manifest::default());
+        }
+        Ok(manifest)
+    }
+
+    pub fn save(&self, directory: &Path, journal: &mut Journal) -> Result<(), String> {
+        let path = directory.join(MANIFEST_FILENAME);
+        if self.icons.is_empty() && !path.exists() {
+            return Ok(());
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|error| format!("Failed to serialize manifest: {}", error))?;
+        journal.write_string(&path, &(content + "\n"))
+    }
+}
+
+pub fn content_hash(content: &str) -> String {
+    let hash = content.bytes().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    });
+    format!("{:016x}", hash)
+}
+
+pub fn file_hash(path: &Path) -> Option<String> {
+    read_to_string(path)
+        .ok()
+        .map(|content| content_hash(&content))
+}
+
+pub fn manifest_key(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part.to_string_lossy().to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn detects_stale_and_hand_edited_entries() {
+        let directory =
+            std::env::temp_dir().join(format!("cozyutils-manifest-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let output = directory.join("Arrow.tsx");
+        fs::write(&output, "generated").unwrap();
+        let entry = ManifestEntry {
+            source_hash: content_hash("<svg/>"),
+            options_hash: "options".to_string(),
+            outputs: BTreeMap::from([("Arrow.tsx".to_string(), content_hash("generated"))]),
+        };
+        let outputs = [("Arrow.tsx".to_string(), output.clone())];
+
+        assert!(entry.is_current(&content_hash("<svg/>"), "options", &outputs));
+        assert!(!entry.is_current(&content_hash("<svg></svg>"), "options", &outputs));
+        assert!(!entry.is_current(&content_hash("<svg/>"), "other", &outputs));
+
+        fs::write(&output, "edited by hand").unwrap();
+        assert!(!entry.is_current(&content_hash("<svg/>"), "options", &outputs));
+
+        fs::remove_file(&output).unwrap();
+        assert!(!entry.is_current(&content_hash("<svg/>"), "options", &outputs));
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
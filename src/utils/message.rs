@@ -101,10 +101,10 @@ pub fn generate_gemini_text(api_key: &str, model: &str, prompt: &str) -> Result<
         .into_json()
         .map_err(|error| format!("Gemini API response parse failed: {}", error))?;
 
-    if let Some(error) = data.error {
-        if let Some(message) = error.message {
-            return Err(format!("Gemini API error: {}", message));
-        }
+    if let Some(error) = data.error
+        && let Some(message) = error.message
+    {
+        return Err(format!("Gemini API error: {}", message));
     }
 
     let text = data
@@ -234,10 +234,10 @@ pub fn copy_to_clipboard(text: &str) -> Result<String, String> {
             Err(_) => continue,
         };
 
-        if let Some(mut stdin) = child.stdin.take() {
-            if stdin.write_all(text.as_bytes()).is_err() {
-                continue;
-            }
+        if let Some(mut stdin) = child.stdin.take()
+            && stdin.write_all(text.as_bytes()).is_err()
+        {
+            continue;
         }
 
         if child.wait().map(|status| status.success()).unwrap_or(false) {
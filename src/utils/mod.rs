@@ -1,3 +1,18 @@
+pub mod colors;
 pub mod config;
+pub mod diff;
+pub mod dimensions;
 pub mod fs;
+pub mod ids;
+pub mod journal;
+pub mod jsx;
+pub mod lint;
+pub mod manifest;
 pub mod message;
+pub mod naming;
+pub mod native;
+pub mod optimize;
+pub mod react;
+pub mod registry;
+pub mod svg;
+pub mod targets;
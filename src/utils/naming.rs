@@ -0,0 +1,393 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use crate::utils::native::NATIVE_ELEMENTS;
+
+const FALLBACK_NAME: &str = "Icon";
+const RESERVED_WORDS: &[&str] = &[
+    "abstract",
+    "any",
+    "arguments",
+    "as",
+    "asserts",
+    "async",
+    "await",
+    "boolean",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "constructor",
+    "continue",
+    "debugger",
+    "declare",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "from",
+    "function",
+    "get",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "infer",
+    "instanceof",
+    "interface",
+    "is",
+    "keyof",
+    "let",
+    "module",
+    "namespace",
+    "never",
+    "new",
+    "null",
+    "number",
+    "object",
+    "of",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "require",
+    "return",
+    "set",
+    "static",
+    "string",
+    "super",
+    "switch",
+    "symbol",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "undefined",
+    "unique",
+    "unknown",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+const RESERVED_GLOBALS: &[&str] = &[
+    "Array", "Boolean", "Date", "Error", "Fragment", "Function", "Infinity", "JSON", "JSX", "Map",
+    "Math", "NaN", "Number", "Object", "Promise", "Proxy", "React", "Reflect", "RegExp", "Set",
+    "String", "SvgProps", "WeakMap", "WeakSet",
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum NameCase {
+    #[default]
+    Pascal,
+    Camel,
+}
+
+impl NameCase {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "pascal" | "pascalcase" => Ok(NameCase::Pascal),
+            "camel" | "camelcase" => Ok(NameCase::Camel),
+            _ => Err(format!(
+                "Invalid case '{}'. Expected pascal or camel.",
+                value
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NameOptions {
+    pub case: NameCase,
+    pub prefix: String,
+    pub suffix: String,
+}
+
+impl NameOptions {
+    pub fn parse(
+        case: Option<&String>,
+        prefix: Option<&String>,
+        suffix: Option<&String>,
+    ) -> Result<Self, String> {
+        let case = match case {
+            Some(value) => NameCase::parse(value)?,
+            None => NameCase::default(),
+        };
+        for (flag, value) in [("prefix", prefix), ("suffix", suffix)] {
+            if let Some(value) = value
+                && split_words(value).is_empty()
+            {
+                return Err(format!(
+                    "Invalid --{} '{}'. Expected letters or digits.",
+                    flag, value
+                ));
+            }
+        }
+        Ok(NameOptions {
+            case,
+            prefix: prefix.cloned().unwrap_or_default(),
+            suffix: suffix.cloned().unwrap_or_default(),
+        })
+    }
+}
+
+fn is_word_char(character: char) -> bool {
+    character.is_alphabetic() || character.is_ascii_digit()
+}
+
+fn split_words(text: &str) -> Vec<&str> {
+    text.split(|character: char| !is_word_char(character))
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn decapitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+pub fn is_reserved_name(name: &str) -> bool {
+    RESERVED_WORDS.contains(&name)
+        || RESERVED_GLOBALS.contains(&name)
+        || NATIVE_ELEMENTS
+            .iter()
+            .any(|(_, native_name)| *native_name == name)
+}
+
+pub fn make_identifier(filename: &str, options: &NameOptions) -> String {
+    let mut words = split_words(&options.prefix);
+    words.extend(split_words(filename));
+    words.extend(split_words(&options.suffix));
+
+    let mut name = words.into_iter().map(capitalize).collect::<String>();
+    if name.is_empty() {
+        name = FALLBACK_NAME.to_string();
+    }
+    if name.starts_with(|character: char| character.is_ascii_digit()) {
+        name = format!("{}{}", FALLBACK_NAME, name);
+    }
+    if options.case == NameCase::Camel {
+        name = decapitalize(&name);
+    }
+    if is_reserved_name(&name) {
+        name.push_str(FALLBACK_NAME);
+    }
+    name
+}
+
+pub fn make_component_name(filename: &str) -> String {
+    make_identifier(filename, &NameOptions::default())
+}
+
+pub fn file_stem(filename: &str) -> &str {
+    Path::new(filename)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or(filename)
+}
+
+fn file_extension(filename: &str) -> &str {
+    Path::new(filename)
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or("")
+}
+
+pub fn unique_identifiers(files: &[String], options: &NameOptions) -> (Vec<String>, Vec<String>) {
+    unique_names(files, "", |text| make_identifier(text, options))
+}
+
+pub fn make_symbol_id(filename: &str) -> String {
+    let id = split_words(filename)
+        .into_iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("-");
+    if id.is_empty() {
+        FALLBACK_NAME.to_lowercase()
+    } else {
+        id
+    }
+}
+
+pub fn kebab_case(name: &str) -> String {
+    split_words(name)
+        .into_iter()
+        .map(|word| {
+            let chars = word.chars().collect::<Vec<_>>();
+            let mut kebab = String::new();
+            for (index, character) in chars.iter().enumerate() {
+                let boundary = index > 0
+                    && character.is_uppercase()
+                    && (!chars[index - 1].is_uppercase()
+                        || chars.get(index + 1).is_some_and(|next| next.is_lowercase()));
+                if boundary {
+                    kebab.push('-');
+                }
+                kebab.extend(character.to_lowercase());
+            }
+            kebab
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+pub fn unique_symbol_ids(files: &[String]) -> (Vec<String>, Vec<String>) {
+    unique_names(files, "-", make_symbol_id)
+}
+
+pub fn unique_kebab_names(files: &[String]) -> (Vec<String>, Vec<String>) {
+    unique_names(files, "-", |name| match kebab_case(name) {
+        kebab if kebab.is_empty() => FALLBACK_NAME.to_lowercase(),
+        kebab => kebab,
+    })
+}
+
+fn unique_names(
+    files: &[String],
+    separator: &str,
+    make_name: impl Fn(&str) -> String,
+) -> (Vec<String>, Vec<String>) {
+    let base_names = files
+        .iter()
+        .map(|file| make_name(file_stem(file)))
+        .collect::<Vec<_>>();
+    let mut groups: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (index, name) in base_names.iter().enumerate() {
+        groups.entry(name.as_str()).or_default().push(index);
+    }
+
+    let mut used = base_names.iter().cloned().collect::<HashSet<_>>();
+    let mut names = base_names.clone();
+    let mut warnings = Vec::new();
+    for (index, base_name) in base_names.iter().enumerate() {
+        let group = &groups[base_name.as_str()];
+        if group[0] == index {
+            continue;
+        }
+
+        let first_extension = file_extension(&files[group[0]]);
+        let extension = file_extension(&files[index]);
+        let mut name = if extension != first_extension && !extension.is_empty() {
+            make_name(&format!("{} {}", file_stem(&files[index]), extension))
+        } else {
+            base_name.clone()
+        };
+        if used.contains(&name) {
+            let mut counter = 2;
+            while used.contains(&format!("{}{}{}", name, separator, counter)) {
+                counter += 1;
+            }
+            name = format!("{}{}{}", name, separator, counter);
+        }
+        used.insert(name.clone());
+        warnings.push(format!(
+            "{} and {} both map to {}; using {} for {}.",
+            files[group[0]], files[index], base_name, name, files[index]
+        ));
+        names[index] = name;
+    }
+    (names, warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(case: NameCase, prefix: &str, suffix: &str) -> NameOptions {
+        NameOptions {
+            case,
+            prefix: prefix.to_string(),
+            suffix: suffix.to_string(),
+        }
+    }
+
+    #[test]
+    fn makes_identifiers_from_filenames() {
+        let default = NameOptions::default();
+        assert_eq!(make_identifier("arrow-left", &default), "ArrowLeft");
+        assert_eq!(
+            make_identifier("24px_home icon", &default),
+            "Icon24pxHomeIcon"
+        );
+        assert_eq!(make_identifier("---", &default), "Icon");
+        assert_eq!(make_identifier("image", &default), "ImageIcon");
+        assert_eq!(
+            make_identifier("arrow-left", &options(NameCase::Camel, "ui", "Icon")),
+            "uiArrowLeftIcon"
+        );
+        assert_eq!(
+            make_identifier("delete", &options(NameCase::Camel, "", "")),
+            "deleteIcon"
+        );
+    }
+
+    fn files(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn disambiguates_colliding_names() {
+        let (names, warnings) = unique_identifiers(
+            &files(&["arrow-left.svg", "arrow_left.svg", "arrow-left.png"]),
+            &NameOptions::default(),
+        );
+        assert_eq!(names, ["ArrowLeft", "ArrowLeft2", "ArrowLeftPng"]);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(
+            warnings[0],
+            "arrow-left.svg and arrow_left.svg both map to ArrowLeft; using ArrowLeft2 for arrow_left.svg."
+        );
+    }
+
+    #[test]
+    fn makes_symbol_ids() {
+        assert_eq!(make_symbol_id("Arrow Left_2"), "arrow-left-2");
+        assert_eq!(make_symbol_id("!!"), "icon");
+    }
+
+    #[test]
+    fn disambiguates_symbol_ids_with_a_separator() {
+        let (ids, warnings) = unique_symbol_ids(&files(&["home.svg", "Home.svg", "home-2.svg"]));
+        assert_eq!(ids, ["home", "home-3", "home-2"]);
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn makes_kebab_names_across_camel_case() {
+        let (names, warnings) = unique_kebab_names(&files(&[
+            "ArrowLeft.svg",
+            "arrow_left.svg",
+            "chevronDown.svg",
+            "!.svg",
+        ]));
+        assert_eq!(
+            names,
+            ["arrow-left", "arrow-left-2", "chevron-down", "icon"]
+        );
+        assert_eq!(warnings.len(), 1);
+    }
+}
@@ -0,0 +1,133 @@
+use crate::utils::jsx::{JsxElement, JsxNode, JsxValue, SVG_ATTRIBUTE_NAMES, jsx_attribute_name};
+
+pub const NATIVE_PROPS_TYPE: &str = "SvgProps";
+pub const NATIVE_ELEMENTS: &[(&str, &str)] = &[
+    ("circle", "Circle"),
+    ("clipPath", "ClipPath"),
+    ("defs", "Defs"),
+    ("ellipse", "Ellipse"),
+    ("feBlend", "FeBlend"),
+    ("feColorMatrix", "FeColorMatrix"),
+    ("feComposite", "FeComposite"),
+    ("feFlood", "FeFlood"),
+    ("feGaussianBlur", "FeGaussianBlur"),
+    ("feMerge", "FeMerge"),
+    ("feMergeNode", "FeMergeNode"),
+    ("feOffset", "FeOffset"),
+    ("filter", "Filter"),
+    ("foreignObject", "ForeignObject"),
+    ("g", "G"),
+    ("image", "Image"),
+    ("line", "Line"),
+    ("linearGradient", "LinearGradient"),
+    ("marker", "Marker"),
+    ("mask", "Mask"),
+    ("path", "Path"),
+    ("pattern", "Pattern"),
+    ("polygon", "Polygon"),
+    ("polyline", "Polyline"),
+    ("radialGradient", "RadialGradient"),
+    ("rect", "Rect"),
+    ("stop", "Stop"),
+    ("svg", "Svg"),
+    ("symbol", "Symbol"),
+    ("text", "Text"),
+    ("textPath", "TextPath"),
+    ("tspan", "TSpan"),
+    ("use", "Use"),
+];
+const DROPPED_ATTRIBUTES: &[&str] = &[
+    "className",
+    "xmlns",
+    "xmlnsXlink",
+    "xmlSpace",
+    "xmlLang",
+    "xmlBase",
+    "version",
+    "enableBackground",
+];
+const STYLE_PROPERTIES: &[&str] = &["fill", "stroke", "opacity", "color", "display", "transform"];
+
+fn native_element_name(name: &str) -> Option<&'static str> {
+    NATIVE_ELEMENTS
+        .iter()
+        .find(|(svg_name, _)| *svg_name == name)
+        .map(|(_, native_name)| *native_name)
+}
+
+fn is_style_property(property: &str) -> bool {
+    STYLE_PROPERTIES.contains(&property)
+        || SVG_ATTRIBUTE_NAMES
+            .iter()
+            .any(|(svg_name, _)| *svg_name == property && !svg_name.contains(':'))
+}
+
+fn convert_element(element: &mut JsxElement, used: &mut Vec<String>) -> bool {
+    let Some(native_name) = native_element_name(&element.name) else {
+        return false;
+    };
+    element.name = native_name.to_string();
+    if !used.iter().any(|name| name == native_name) {
+        used.push(native_name.to_string());
+    }
+
+    let mut flattened = Vec::new();
+    let mut attributes = Vec::new();
+    for (name, value) in element.attributes.drain(..) {
+        if DROPPED_ATTRIBUTES.contains(&name.as_str())
+            || name.starts_with("data-")
+            || name.starts_with("aria-")
+        {
+            continue;
+        }
+        match (name.as_str(), value) {
+            ("style", JsxValue::Style(entries)) => {
+                for (property, entry) in entries {
+                    if is_style_property(&property) {
+                        flattened.push((jsx_attribute_name(&property), entry));
+                    }
+                }
+            }
+            ("xlinkHref", value) => attributes.push(("href".to_string(), value)),
+            (_, value) => attributes.push((name, value)),
+        }
+    }
+    for (name, value) in flattened {
+        if !attributes.iter().any(|(existing, _)| *existing == name) {
+            attributes.push((name, value));
+        }
+    }
+    element.attributes = attributes;
+
+    let allows_text = matches!(native_name, "Text" | "TSpan" | "TextPath");
+    let children = std::mem::take(&mut element.children);
+    for mut child in children {
+        let keep = match &mut child {
+            JsxNode::Element(child_element) => convert_element(child_element, used),
+            JsxNode::Text(_) => allows_text,
+            JsxNode::RawText(_) => false,
+            JsxNode::Expression(_) => true,
+        };
+        if keep {
+            element.children.push(child);
+        }
+    }
+    true
+}
+
+pub fn to_react_native(element: &mut JsxElement) -> Vec<String> {
+    let mut used = Vec::new();
+    convert_element(element, &mut used);
+    used.retain(|name| name != "Svg");
+    used.sort();
+
+    let mut named = used;
+    named.push(NATIVE_PROPS_TYPE.to_string());
+    vec![
+        "import React from \"react\";".to_string(),
+        format!(
+            "import Svg, {{ {} }} from \"react-native-svg\";",
+            named.join(", ")
+        ),
+    ]
+}
@@ -0,0 +1,338 @@
+use crate::utils::svg::{Document, Element, Node, parse_style};
+
+pub const OPTIMIZE_PASSES: &[&str] = &[
+    "comments",
+    "doctype",
+    "metadata",
+    "editors",
+    "groups",
+    "precision",
+    "defaults",
+];
+const DEFAULT_PRECISION: usize = 3;
+const EDITOR_PREFIXES: &[&str] = &[
+    "sodipodi", "inkscape", "sketch", "serif", "figma", "dc", "cc", "rdf",
+];
+const PRECISION_ATTRIBUTES: &[&str] = &[
+    "d",
+    "points",
+    "transform",
+    "gradientTransform",
+    "patternTransform",
+    "viewBox",
+    "x",
+    "y",
+    "x1",
+    "y1",
+    "x2",
+    "y2",
+    "cx",
+    "cy",
+    "r",
+    "rx",
+    "ry",
+    "fx",
+    "fy",
+    "width",
+    "height",
+    "offset",
+    "stroke-width",
+];
+const INHERITED_DEFAULTS: &[(&str, &str)] = &[
+    ("clip-rule", "nonzero"),
+    ("fill-opacity", "1"),
+    ("fill-rule", "nonzero"),
+    ("stroke-dasharray", "none"),
+    ("stroke-dashoffset", "0"),
+    ("stroke-linecap", "butt"),
+    ("stroke-linejoin", "miter"),
+    ("stroke-miterlimit", "4"),
+    ("stroke-opacity", "1"),
+    ("stroke-width", "1"),
+    ("visibility", "visible"),
+];
+const ELEMENT_DEFAULTS: &[(&str, &str)] = &[("display", "inline"), ("opacity", "1")];
+const POSITION_DEFAULT_ELEMENTS: &[&str] = &["rect", "image", "use", "foreignObject"];
+
+#[derive(Debug, Clone)]
+pub struct OptimizeOptions {
+    pub passes: Vec<String>,
+    pub precision: usize,
+}
+
+impl Default for OptimizeOptions {
+    fn default() -> Self {
+        OptimizeOptions {
+            passes: OPTIMIZE_PASSES
+                .iter()
+                .map(|pass| pass.to_string())
+                .collect(),
+            precision: DEFAULT_PRECISION,
+        }
+    }
+}
+
+impl OptimizeOptions {
+    pub fn parse(passes: Option<&str>, precision: Option<&str>) -> Result<Self, String> {
+        let mut options = OptimizeOptions::default();
+
+        if let Some(value) = passes {
+            options.passes = match value.trim() {
+                "all" | "true" => options.passes,
+                "none" | "false" => Vec::new(),
+                list => list
+                    .split(',')
+                    .map(|pass| pass.trim())
+                    .filter(|pass| !pass.is_empty())
+                    .map(|pass| {
+                        if OPTIMIZE_PASSES.contains(&pass) {
+                            Ok(pass.to_string())
+                        } else {
+                            Err(format!(
+                                "Unknown optimize pass '{}'. Use all, none, or any of: {}.",
+                                pass,
+                                OPTIMIZE_PASSES.join(", ")
+                            ))
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            };
+        }
+
+        if let Some(value) = precision {
+            options.precision = value
+                .trim()
+                .parse()
+                .map_err(|_| format!("Invalid precision '{}'. Expected a number.", value))?;
+        }
+
+        Ok(options)
+    }
+
+    fn enabled(&self, pass: &str) -> bool {
+        self.passes.iter().any(|enabled| enabled == pass)
+    }
+}
+
+pub fn optimize(document: &mut Document, options: &OptimizeOptions) {
+    document.nodes.retain(|node| match node {
+        Node::Comment(_) => !options.enabled("comments"),
+        Node::Doctype(_) | Node::Instruction(_) => !options.enabled("doctype"),
+        _ => true,
+    });
+
+    let Some(root) = document.root_mut() else {
+        return;
+    };
+    optimize_element(root, options);
+    if options.enabled("defaults") {
+        remove_default_attributes(root, &[]);
+    }
+}
+
+fn optimize_element(element: &mut Element, options: &OptimizeOptions) {
+    if options.enabled("editors") {
+        element.attributes.retain(|(name, _)| !is_editor_name(name));
+    }
+
+    if options.enabled("precision") {
+        for (name, value) in element.attributes.iter_mut() {
+            if PRECISION_ATTRIBUTES.contains(&name.as_str()) {
+                *value = round_numbers(value, options.precision);
+            }
+        }
+    }
+
+    element.children.retain(|child| match child {
+        Node::Comment(_) => !options.enabled("comments"),
+        Node::Doctype(_) | Node::Instruction(_) => !options.enabled("doctype"),
+        Node::Element(child) if options.enabled("metadata") && child.name == "metadata" => false,
+        Node::Element(child) if options.enabled("editors") && is_editor_name(&child.name) => false,
+        _ => true,
+    });
+
+    for child in element.children.iter_mut() {
+        if let Node::Element(child) = child {
+            optimize_element(child, options);
+        }
+    }
+
+    if options.enabled("groups") {
+        collapse_groups(element);
+    }
+}
+
+fn is_editor_name(name: &str) -> bool {
+    let prefix = name
+        .strip_prefix("xmlns:")
+        .unwrap_or_else(|| name.split(':').next().unwrap_or(""));
+    name.contains(':') && EDITOR_PREFIXES.contains(&prefix)
+}
+
+fn has_element_children(element: &Element) -> bool {
+    element.children.iter().any(|child| match child {
+        Node::Element(_) => true,
+        Node::Text(text) | Node::CData(text) => !text.trim().is_empty(),
+        _ => false,
+    })
+}
+
+fn collapse_groups(element: &mut Element) {
+    let children = std::mem::take(&mut element.children);
+    for child in children {
+        match child {
+            Node::Element(group)
+                if matches!(group.name.as_str(), "g" | "defs") && !has_element_children(&group) =>
+            {
+                continue;
+            }
+            Node::Element(group) if group.name == "g" && group.attributes.is_empty() => {
+                element.children.extend(group.children);
+            }
+            other => element.children.push(other),
+        }
+    }
+}
+
+fn remove_default_attributes(element: &mut Element, inherited: &[(String, String)]) {
+    let is_position_default = |name: &str, value: &str| {
+        (name == "x" || name == "y")
+            && value == "0"
+            && POSITION_DEFAULT_ELEMENTS.contains(&element.name.as_str())
+    };
+    let removable = element
+        .attributes
+        .iter()
+        .filter(|(name, value)| {
+            let inherited_default = INHERITED_DEFAULTS
+                .iter()
+                .find(|(property, _)| property == name)
+                .is_some_and(|(_, default)| {
+                    value == default
+                        && inherited
+                            .iter()
+                            .rev()
+                            .find(|(property, _)| property == name)
+                            .is_none_or(|(_, parent)| parent == default)
+                });
+            let element_default = ELEMENT_DEFAULTS
+                .iter()
+                .any(|(property, default)| property == name && value == default);
+            inherited_default || element_default || is_position_default(name, value)
+        })
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+    element
+        .attributes
+        .retain(|(name, _)| !removable.contains(name));
+
+    let mut scope = inherited.to_vec();
+    for (name, value) in &element.attributes {
+        if name == "style" {
+            scope.extend(parse_style(value));
+        } else if INHERITED_DEFAULTS
+            .iter()
+            .any(|(property, _)| property == name)
+        {
+            scope.push((name.clone(), value.clone()));
+        }
+    }
+
+    for child in element.children.iter_mut() {
+        if let Node::Element(child) = child {
+            remove_default_attributes(child, &scope);
+        }
+    }
+}
+
+pub fn round_numbers(value: &str, precision: usize) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    let mut output = String::with_capacity(value.len());
+    let mut index = 0;
+
+    while index < chars.len() {
+        let starts_number = chars[index].is_ascii_digit()
+            || (chars[index] == '.' && chars.get(index + 1).is_some_and(char::is_ascii_digit));
+        if !starts_number {
+            output.push(chars[index]);
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        let mut seen_dot = false;
+        while index < chars.len()
+            && (chars[index].is_ascii_digit() || (chars[index] == '.' && !seen_dot))
+        {
+            seen_dot |= chars[index] == '.';
+            index += 1;
+        }
+        let has_exponent = matches!(chars.get(index), Some('e' | 'E'))
+            && chars
+                .get(index + 1)
+                .is_some_and(|ch| ch.is_ascii_digit() || *ch == '-' || *ch == '+');
+        let token: String = chars[start..index].iter().collect();
+        let decimals = token.split_once('.').map(|(_, fraction)| fraction.len());
+
+        if has_exponent || decimals.is_none_or(|decimals| decimals <= precision) {
+            output.push_str(&token);
+            continue;
+        }
+
+        let rounded = format_number(token.parse::<f64>().unwrap_or(0.0), precision);
+        if rounded == "0" && output.ends_with('-') {
+            output.pop();
+            if output.ends_with(|ch: char| ch.is_ascii_digit() || ch == '.') {
+                output.push(' ');
+            }
+        }
+        let needs_separator =
+            !rounded.contains('.') && chars.get(index) == Some(&'.') && !rounded.is_empty();
+        output.push_str(&rounded);
+        if needs_separator {
+            output.push(' ');
+        }
+    }
+
+    output
+}
+
+fn format_number(value: f64, precision: usize) -> String {
+    let formatted = format!("{:.*}", precision, value);
+    let trimmed = if formatted.contains('.') {
+        formatted.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        formatted.as_str()
+    };
+    match trimmed {
+        "" | "-0" => "0".to_string(),
+        _ => trimmed.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_numbers_to_precision() {
+        assert_eq!(round_numbers("M1.23456 7.8901", 3), "M1.235 7.89");
+        assert_eq!(
+            round_numbers("translate(10.0004, 2.5)", 3),
+            "translate(10, 2.5)"
+        );
+        assert_eq!(round_numbers("0.1234", 2), "0.12");
+    }
+
+    #[test]
+    fn keeps_short_numbers_and_exponents() {
+        assert_eq!(round_numbers("M12 0.5h1.25", 3), "M12 0.5h1.25");
+        assert_eq!(round_numbers("1.23456e-5", 3), "1.23456e-5");
+    }
+
+    #[test]
+    fn keeps_adjacent_numbers_apart() {
+        assert_eq!(round_numbers("M-0.0001-0.0002", 3), "M0 0");
+        assert_eq!(round_numbers("1.00001.5", 3), "1 .5");
+    }
+}
@@ -0,0 +1,130 @@
+use crate::utils::fs::{Component, ComponentProp};
+use crate::utils::jsx::{JsxElement, JsxNode, JsxValue, json_string};
+
+const WEB_REF_TYPE: &str = "SVGSVGElement";
+const NATIVE_REF_TYPE: &str = "Svg";
+const TITLE_ID_VARIABLE: &str = "labelId";
+
+#[derive(Debug, Clone, Default)]
+pub struct ReactOptions {
+    pub forward_ref: bool,
+    pub memo: bool,
+    pub size: bool,
+    pub default_size: Option<String>,
+    pub title: bool,
+}
+
+impl ReactOptions {
+    pub fn parse_size(value: &str) -> Result<Option<String>, String> {
+        match value.trim() {
+            "true" => Ok(None),
+            size if size.parse::<f64>().is_ok() => Ok(Some(size.to_string())),
+            _ => Err(format!("Invalid size '{}'. Expected a number.", value)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.forward_ref && !self.memo && !self.size && !self.title
+    }
+}
+
+fn string_prop(name: &str) -> ComponentProp {
+    ComponentProp {
+        name: name.to_string(),
+        type_name: "string".to_string(),
+        default: None,
+    }
+}
+
+pub fn apply_react_options(
+    jsx: &mut JsxElement,
+    component: &mut Component,
+    options: &ReactOptions,
+    native: bool,
+) {
+    if options.forward_ref {
+        let ref_type = if native {
+            NATIVE_REF_TYPE
+        } else {
+            WEB_REF_TYPE
+        };
+        component.ref_type = Some(ref_type.to_string());
+        jsx.set_attribute("ref", JsxValue::Expression("ref".to_string()));
+    }
+    component.memo = options.memo;
+
+    if options.size {
+        component.props.push(ComponentProp {
+            name: "size".to_string(),
+            type_name: "number | string".to_string(),
+            default: options.default_size.clone(),
+        });
+        for dimension in ["width", "height"] {
+            let original = jsx
+                .attributes
+                .iter()
+                .find(|(name, _)| name == dimension)
+                .and_then(|(_, value)| match value {
+                    JsxValue::String(text) => Some(text.clone()),
+                    _ => None,
+                });
+            let expression = match original {
+                Some(original) if options.default_size.is_none() => {
+                    format!("size ?? {}", json_string(&original))
+                }
+                _ => "size".to_string(),
+            };
+            jsx.set_attribute(dimension, JsxValue::Expression(expression));
+        }
+    }
+
+    if options.title {
+        apply_title(jsx, component, native);
+    }
+}
+
+fn apply_title(jsx: &mut JsxElement, component: &mut Component, native: bool) {
+    component.props.push(string_prop("title"));
+    jsx.children
+        .retain(|child| !matches!(child, JsxNode::Element(element) if element.name == "title"));
+    jsx.attributes
+        .retain(|(name, _)| name != "aria-labelledby" && name != "aria-hidden");
+
+    if native {
+        jsx.set_attribute("accessibilityRole", JsxValue::String("image".to_string()));
+        jsx.set_attribute(
+            "accessibilityLabel",
+            JsxValue::Expression("title".to_string()),
+        );
+        jsx.set_attribute(
+            "accessible",
+            JsxValue::Expression("title !== undefined".to_string()),
+        );
+        return;
+    }
+
+    component.props.push(string_prop("titleId"));
+    component
+        .statements
+        .push("const generatedTitleId = React.useId();".to_string());
+    component.statements.push(format!(
+        "const {} = title ? titleId ?? generatedTitleId : undefined;",
+        TITLE_ID_VARIABLE
+    ));
+    jsx.set_attribute("role", JsxValue::String("img".to_string()));
+    jsx.set_attribute(
+        "aria-labelledby",
+        JsxValue::Expression(TITLE_ID_VARIABLE.to_string()),
+    );
+    jsx.set_attribute(
+        "aria-hidden",
+        JsxValue::Expression("title ? undefined : true".to_string()),
+    );
+    jsx.children.insert(
+        0,
+        JsxNode::Expression(format!(
+            "title ? <title id={{{}}}>{{title}}</title> : null",
+            TITLE_ID_VARIABLE
+        )),
+    );
+}
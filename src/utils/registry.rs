@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+
+use crate::utils::fs::WEB_PROPS_TYPE;
+use crate::utils::jsx::json_string;
+use crate::utils::naming::make_component_name;
+use crate::utils::native::NATIVE_PROPS_TYPE;
+use crate::utils::targets::Target;
+
+pub const REGISTRY_FILENAME: &str = "icons.ts";
+pub const LAZY_REGISTRY_FILENAME: &str = "icons.lazy.ts";
+
+#[derive(Debug, Clone)]
+pub struct RegistryEntry {
+    pub key: String,
+    pub name: String,
+    pub path: String,
+}
+
+fn import_names(entries: &[RegistryEntry]) -> Vec<String> {
+    let mut used = HashSet::new();
+    entries
+        .iter()
+        .map(|entry| {
+            let base_name = make_component_name(&entry.name);
+            let mut name = base_name.clone();
+            let mut counter = 2;
+            while !used.insert(name.clone()) {
+                name = format!("{}{}", base_name, counter);
+                counter += 1;
+            }
+            name
+        })
+        .collect()
+}
+
+fn icon_name_union(entries: &[RegistryEntry]) -> String {
+    let members = entries
+        .iter()
+        .map(|entry| format!("  | {}", json_string(&entry.key)))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("export type IconName =\n{};", members)
+}
+
+pub fn registry_content(entries: &[RegistryEntry], target: Target) -> String {
+    let names = import_names(entries);
+    let mut imports = Vec::new();
+    if target.is_react() {
+        imports.push("import React from \"react\";".to_string());
+    }
+    if target == Target::ReactNative {
+        imports.push(format!(
+            "import type {{ {} }} from \"react-native-svg\";",
+            NATIVE_PROPS_TYPE
+        ));
+    }
+    imports.extend(
+        entries
+            .iter()
+            .zip(&names)
+            .map(|(entry, name)| format!("import {} from {};", name, json_string(&entry.path))),
+    );
+
+    let map = entries
+        .iter()
+        .zip(&names)
+        .map(|(entry, name)| format!("  {}: {},", json_string(&entry.key), name))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut sections = vec![
+        imports.join("\n"),
+        icon_name_union(entries),
+        format!(
+            "export const icons = {{\n{}\n}} satisfies Record<IconName, unknown>;",
+            map
+        ),
+    ];
+
+    if target.is_react() {
+        let props_type = if target == Target::ReactNative {
+            NATIVE_PROPS_TYPE
+        } else {
+            WEB_PROPS_TYPE
+        };
+        sections.push(format!(
+            "export type IconProps = {} & {{ name: IconName }};",
+            props_type
+        ));
+        sections.push(
+            "export function Icon({ name, ...props }: IconProps) {\n  const Component = icons[name] as React.ComponentType<Omit<IconProps, \"name\">>;\n  return React.createElement(Component, props);\n}"
+                .to_string(),
+        );
+    }
+    sections.join("\n\n") + "\n"
+}
+
+pub fn lazy_registry_content(entries: &[RegistryEntry]) -> String {
+    let map = entries
+        .iter()
+        .map(|entry| {
+            format!(
+                "  {}: React.lazy(() => import({})),",
+                json_string(&entry.key),
+                json_string(&entry.path)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "import React from \"react\";\nimport type {{ IconName }} from {};\n\nexport const lazyIcons = {{\n{}\n}} satisfies Record<IconName, unknown>;\n",
+        json_string(&format!("./{}", REGISTRY_FILENAME)),
+        map
+    )
+}
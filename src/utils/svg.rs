@@ -0,0 +1,516 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum Node {
+    Element(Element),
+    Text(String),
+    CData(String),
+    Comment(String),
+    Instruction(String),
+    Doctype(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Element {
+    pub name: String,
+    pub attributes: Vec<(String, String)>,
+    pub children: Vec<Node>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Document {
+    pub nodes: Vec<Node>,
+}
+
+impl Element {
+    pub fn new(name: &str) -> Self {
+        Element {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn set_attribute(&mut self, name: &str, value: &str) {
+        match self.attributes.iter_mut().find(|(key, _)| key == name) {
+            Some((_, existing)) => *existing = value.to_string(),
+            None => self.attributes.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    pub fn remove_attribute(&mut self, name: &str) -> Option<String> {
+        let index = self.attributes.iter().position(|(key, _)| key == name)?;
+        Some(self.attributes.remove(index).1)
+    }
+
+    pub fn visit(&self, visitor: &mut impl FnMut(&Element)) {
+        visitor(self);
+        for child in &self.children {
+            if let Node::Element(element) = child {
+                element.visit(visitor);
+            }
+        }
+    }
+
+    pub fn visit_mut(&mut self, visitor: &mut impl FnMut(&mut Element)) {
+        visitor(self);
+        for child in self.children.iter_mut() {
+            if let Node::Element(element) = child {
+                element.visit_mut(visitor);
+            }
+        }
+    }
+}
+
+impl Document {
+    pub fn root(&self) -> Option<&Element> {
+        self.nodes.iter().find_map(|node| match node {
+            Node::Element(element) => Some(element),
+            _ => None,
+        })
+    }
+
+    pub fn root_mut(&mut self) -> Option<&mut Element> {
+        self.nodes.iter_mut().find_map(|node| match node {
+            Node::Element(element) => Some(element),
+            _ => None,
+        })
+    }
+}
+
+pub fn parse_svg(content: &str) -> Result<Document, String> {
+    let mut parser = Parser {
+        source: content.strip_prefix('\u{feff}').unwrap_or(content),
+        position: 0,
+    };
+    let nodes = parser.parse_nodes(None)?;
+    let document = Document { nodes };
+
+    match document.root() {
+        Some(root) if root.name == "svg" => Ok(document),
+        Some(root) => Err(format!(
+            "Expected <svg> root element, found <{}>",
+            root.name
+        )),
+        None => Err("No <svg> element found".to_string()),
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.source[self.position..]
+    }
+
+    fn error(&self, message: &str) -> String {
+        let line = self.source[..self.position].matches('\n').count() + 1;
+        format!("Invalid SVG at line {}: {}", line, message)
+    }
+
+    fn take_until(&mut self, terminator: &str, what: &str) -> Result<&'a str, String> {
+        let rest = self.rest();
+        match rest.find(terminator) {
+            Some(index) => {
+                self.position += index + terminator.len();
+                Ok(&rest[..index])
+            }
+            None => Err(self.error(&format!("unterminated {}", what))),
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let end = rest.find(|ch: char| !predicate(ch)).unwrap_or(rest.len());
+        self.position += end;
+        &rest[..end]
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    fn parse_nodes(&mut self, parent: Option<&str>) -> Result<Vec<Node>, String> {
+        let mut nodes = Vec::new();
+
+        loop {
+            let rest = self.rest();
+            if rest.is_empty() {
+                return match parent {
+                    Some(name) => Err(self.error(&format!("unclosed <{}> element", name))),
+                    None => Ok(nodes),
+                };
+            }
+
+            if rest.starts_with("</") {
+                self.position += 2;
+                let name = self.take_until(">", "closing tag")?.trim();
+                return match parent {
+                    Some(expected) if expected == name => Ok(nodes),
+                    Some(expected) => {
+                        Err(self.error(&format!("expected </{}> but found </{}>", expected, name)))
+                    }
+                    None => Err(self.error(&format!("unexpected </{}>", name))),
+                };
+            }
+
+            if rest.starts_with("<!--") {
+                self.position += 4;
+                let comment = self.take_until("-->", "comment")?;
+                nodes.push(Node::Comment(comment.to_string()));
+            } else if rest.starts_with("<![CDATA[") {
+                self.position += 9;
+                let data = self.take_until("]]>", "CDATA section")?;
+                nodes.push(Node::CData(data.to_string()));
+            } else if rest.starts_with("<!") {
+                nodes.push(Node::Doctype(self.parse_doctype()?));
+            } else if rest.starts_with("<?") {
+                self.position += 2;
+                let instruction = self.take_until("?>", "processing instruction")?;
+                nodes.push(Node::Instruction(instruction.to_string()));
+            } else if rest.starts_with('<') {
+                nodes.push(Node::Element(self.parse_element()?));
+            } else {
+                let text = self.take_while(|ch| ch != '<');
+                nodes.push(Node::Text(decode_entities(text)));
+            }
+        }
+    }
+
+    fn parse_doctype(&mut self) -> Result<String, String> {
+        let rest = self.rest();
+        let mut depth = 0usize;
+        for (index, ch) in rest.char_indices() {
+            match ch {
+                '[' => depth += 1,
+                ']' => depth = depth.saturating_sub(1),
+                '>' if depth == 0 => {
+                    self.position += index + 1;
+                    return Ok(rest[2..index].to_string());
+                }
+                _ => {}
+            }
+        }
+        Err(self.error("unterminated doctype"))
+    }
+
+    fn parse_element(&mut self) -> Result<Element, String> {
+        self.position += 1;
+        let name = self.take_while(is_name_char);
+        if name.is_empty() {
+            return Err(self.error("expected element name after '<'"));
+        }
+        let mut element = Element::new(name);
+
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.starts_with("/>") {
+                self.position += 2;
+                return Ok(element);
+            }
+            if rest.starts_with('>') {
+                self.position += 1;
+                element.children = self.parse_nodes(Some(name))?;
+                return Ok(element);
+            }
+            if rest.is_empty() {
+                return Err(self.error(&format!("unterminated <{}> tag", name)));
+            }
+
+            let key = self.take_while(is_name_char);
+            if key.is_empty() {
+                return Err(self.error(&format!("invalid attribute in <{}> tag", name)));
+            }
+            self.skip_whitespace();
+            if !self.rest().starts_with('=') {
+                return Err(self.error(&format!("attribute '{}' has no value", key)));
+            }
+            self.position += 1;
+            self.skip_whitespace();
+
+            let quote = match self.rest().chars().next() {
+                Some(ch @ ('"' | '\'')) => ch,
+                _ => return Err(self.error(&format!("attribute '{}' is not quoted", key))),
+            };
+            self.position += 1;
+            let value = self.take_until(&quote.to_string(), "attribute value")?;
+            element
+                .attributes
+                .push((key.to_string(), decode_entities(value)));
+        }
+    }
+}
+
+fn is_name_char(ch: char) -> bool {
+    ch.is_alphanumeric() || matches!(ch, '-' | '_' | ':' | '.')
+}
+
+pub fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(index) = rest.find('&') {
+        decoded.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        let entity = rest
+            .find(';')
+            .map(|end| (&rest[1..end], end))
+            .and_then(|(name, end)| decode_entity(name).map(|ch| (ch, end)));
+        match entity {
+            Some((ch, end)) => {
+                decoded.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+fn decode_entity(name: &str) -> Option<char> {
+    match name {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ => {
+            let code = if let Some(hex) = name.strip_prefix("#x").or(name.strip_prefix("#X")) {
+                u32::from_str_radix(hex, 16).ok()?
+            } else {
+                name.strip_prefix('#')?.parse().ok()?
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+pub fn parse_style(style: &str) -> Vec<(String, String)> {
+    let mut declarations = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut depth = 0usize;
+
+    for ch in style.chars().chain(std::iter::once(';')) {
+        match (ch, quote) {
+            (_, Some(open)) if ch == open => quote = None,
+            (_, Some(_)) => {}
+            ('"' | '\'', None) => quote = Some(ch),
+            ('(', None) => depth += 1,
+            (')', None) => depth = depth.saturating_sub(1),
+            (';', None) if depth == 0 => {
+                if let Some((property, value)) = current.split_once(':') {
+                    let property = property.trim();
+                    let value = value.trim().trim_end_matches("!important").trim_end();
+                    if !property.is_empty() && !value.is_empty() {
+                        let property = if property.starts_with("--") {
+                            property.to_string()
+                        } else {
+                            property.to_lowercase()
+                        };
+                        declarations.push((property, value.to_string()));
+                    }
+                }
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(ch);
+    }
+
+    declarations
+}
+
+pub fn format_style(declarations: &[(String, String)]) -> String {
+    declarations
+        .iter()
+        .map(|(property, value)| format!("{}:{}", property, value))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+pub fn write_element(element: &Element, depth: usize) -> String {
+    let indent = "  ".repeat(depth);
+    let attributes = element
+        .attributes
+        .iter()
+        .map(|(name, value)| format!(" {}=\"{}\"", name, escape_xml(value, true)))
+        .collect::<String>();
+    let children = element
+        .children
+        .iter()
+        .filter(|child| !matches!(child, Node::Text(text) if text.trim().is_empty()))
+        .filter(|child| matches!(child, Node::Element(_) | Node::Text(_) | Node::CData(_)))
+        .collect::<Vec<_>>();
+    if children.is_empty() {
+        return format!("{}<{}{} />", indent, element.name, attributes);
+    }
+
+    if children
+        .iter()
+        .all(|child| !matches!(child, Node::Element(_)))
+    {
+        let content = children
+            .iter()
+            .map(|child| write_node(child, 0))
+            .collect::<String>();
+        return format!(
+            "{}<{}{}>{}</{}>",
+            indent, element.name, attributes, content, element.name
+        );
+    }
+
+    let content = children
+        .iter()
+        .map(|child| write_node(child, depth + 1))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "{}<{}{}>\n{}\n{}</{}>",
+        indent, element.name, attributes, content, indent, element.name
+    )
+}
+
+fn write_node(node: &Node, depth: usize) -> String {
+    let indent = "  ".repeat(depth);
+    match node {
+        Node::Element(element) => write_element(element, depth),
+        Node::Text(text) => format!("{}{}", indent, escape_xml(text.trim(), false)),
+        Node::CData(data) => format!("{}<![CDATA[{}]]>", indent, data),
+        _ => String::new(),
+    }
+}
+
+pub fn escape_xml(text: &str, attribute: bool) -> String {
+    let escaped = text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    if attribute {
+        escaped.replace('"', "&quot;")
+    } else {
+        escaped
+    }
+}
+
+pub fn is_script_element(element: &Element) -> bool {
+    element.name.eq_ignore_ascii_case("script")
+}
+
+pub fn is_event_handler(name: &str) -> bool {
+    name.len() > 2 && name[..2].eq_ignore_ascii_case("on")
+}
+
+pub fn is_javascript_link(name: &str, value: &str) -> bool {
+    let url = value
+        .chars()
+        .filter(|ch| !ch.is_ascii_whitespace() && !ch.is_control())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    (name.eq_ignore_ascii_case("href") || name.eq_ignore_ascii_case("xlink:href"))
+        && url.starts_with("javascript:")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_nested_elements_and_attributes() {
+        let document = parse_svg(
+            "<?xml version=\"1.0\"?>\n<svg viewBox='0 0 24 24'><g fill=\"a &amp; b\"><path d=\"M0 0\"/></g></svg>",
+        )
+        .unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(root.name, "svg");
+        assert_eq!(root.attribute("viewBox"), Some("0 0 24 24"));
+        let Node::Element(group) = &root.children[0] else {
+            panic!("expected <g>");
+        };
+        assert_eq!(group.attribute("fill"), Some("a & b"));
+        assert_eq!(
+            group.children,
+            vec![Node::Element(Element {
+                name: "path".to_string(),
+                attributes: vec![("d".to_string(), "M0 0".to_string())],
+                children: Vec::new(),
+            })]
+        );
+    }
+
+    #[test]
+    fn keeps_cdata_and_comments() {
+        let document =
+            parse_svg("<svg><!-- note --><style><![CDATA[.a{}]]></style></svg>").unwrap();
+        let root = document.root().unwrap();
+        assert_eq!(root.children[0], Node::Comment(" note ".to_string()));
+        let Node::Element(style) = &root.children[1] else {
+            panic!("expected <style>");
+        };
+        assert_eq!(style.children, vec![Node::CData(".a{}".to_string())]);
+    }
+
+    #[test]
+    fn rejects_malformed_markup() {
+        let cases = [
+            ("<svg><g></svg>", "expected </g> but found </svg>"),
+            ("<svg><path d=\"M0 0\">", "unclosed <path> element"),
+            ("<svg width=24></svg>", "attribute 'width' is not quoted"),
+            ("<svg hidden></svg>", "attribute 'hidden' has no value"),
+            ("<svg></svg></g>", "unexpected </g>"),
+            ("<svg><!-- open</svg>", "unterminated comment"),
+        ];
+        for (content, message) in cases {
+            let error = parse_svg(content).unwrap_err();
+            assert!(error.contains(message), "{}: {}", content, error);
+        }
+    }
+
+    #[test]
+    fn reports_error_lines() {
+        let error = parse_svg("<svg>\n<g>\n</svg>").unwrap_err();
+        assert!(error.starts_with("Invalid SVG at line 3:"), "{}", error);
+    }
+
+    #[test]
+    fn requires_an_svg_root() {
+        assert_eq!(
+            parse_svg("<html></html>").unwrap_err(),
+            "Expected <svg> root element, found <html>"
+        );
+        assert_eq!(
+            parse_svg("just text").unwrap_err(),
+            "No <svg> element found"
+        );
+    }
+
+    #[test]
+    fn sets_and_removes_attributes() {
+        let mut element = Element::new("svg");
+        element.set_attribute("width", "24");
+        element.set_attribute("width", "1em");
+        assert_eq!(
+            element.attributes,
+            vec![("width".to_string(), "1em".to_string())]
+        );
+        assert_eq!(element.remove_attribute("width"), Some("1em".to_string()));
+        assert_eq!(element.attribute("width"), None);
+    }
+}
@@ -0,0 +1,315 @@
+use crate::utils::fs::{Component, component_template};
+use crate::utils::ids::ID_PREFIX_VARIABLE;
+use crate::utils::jsx::{
+    JsxElement, JsxNode, JsxValue, Markup, remove_namespaced_attributes, render_markup,
+};
+use crate::utils::native::{NATIVE_PROPS_TYPE, to_react_native};
+use crate::utils::react::{ReactOptions, apply_react_options};
+
+const SOLID_PROPS_TYPE: &str = "JSX.SvgSVGAttributes<SVGSVGElement>";
+const SVELTE_PROPS_TYPE: &str = "SVGAttributes<SVGSVGElement>";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Target {
+    Web,
+    ReactNative,
+    Solid,
+    Vue,
+    Svelte,
+}
+
+impl Target {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.trim() {
+            "web" | "react" => Ok(Target::Web),
+            "react-native" | "native" => Ok(Target::ReactNative),
+            "solid" => Ok(Target::Solid),
+            "vue" => Ok(Target::Vue),
+            "svelte" => Ok(Target::Svelte),
+            _ => Err(format!(
+                "Unsupported target '{}'. Use 'web', 'react-native', 'solid', 'vue', or 'svelte'.",
+                value
+            )),
+        }
+    }
+
+    pub fn parse_list(value: &str) -> Result<Vec<Self>, String> {
+        let mut targets = Vec::new();
+        for target in value.split(',').map(Target::parse) {
+            let target = target?;
+            if !targets.contains(&target) {
+                targets.push(target);
+            }
+        }
+        Ok(targets)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Target::Web => "web",
+            Target::ReactNative => "react-native",
+            Target::Solid => "solid",
+            Target::Vue => "vue",
+            Target::Svelte => "svelte",
+        }
+    }
+
+    pub fn subdirectory(self) -> &'static str {
+        match self {
+            Target::Web => "",
+            Target::ReactNative => "native",
+            Target::Solid => "solid",
+            Target::Vue => "vue",
+            Target::Svelte => "svelte",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Target::Web | Target::ReactNative | Target::Solid => ".tsx",
+            Target::Vue => ".vue",
+            Target::Svelte => ".svelte",
+        }
+    }
+
+    pub fn supports_templates(self) -> bool {
+        matches!(self, Target::Web | Target::ReactNative | Target::Solid)
+    }
+
+    pub fn supports_style_expressions(self) -> bool {
+        matches!(self, Target::Web | Target::ReactNative | Target::Solid)
+    }
+
+    pub fn is_react(self) -> bool {
+        matches!(self, Target::Web | Target::ReactNative)
+    }
+
+    pub fn supports_css_variables(self) -> bool {
+        self != Target::ReactNative
+    }
+
+    pub fn render(
+        self,
+        jsx: &JsxElement,
+        component: &Component,
+        unique_ids: bool,
+        react_options: &ReactOptions,
+    ) -> String {
+        match self {
+            Target::Web => {
+                let mut jsx = jsx.clone();
+                let mut component = component.clone();
+                apply_react_options(&mut jsx, &mut component, react_options, false);
+                react_template(&jsx, component, unique_ids)
+            }
+            Target::ReactNative => {
+                let mut jsx = jsx.clone();
+                let mut component = component.clone();
+                component.imports = to_react_native(&mut jsx);
+                component.props_type = NATIVE_PROPS_TYPE.to_string();
+                apply_react_options(&mut jsx, &mut component, react_options, true);
+                react_template(&jsx, component, unique_ids)
+            }
+            Target::Solid => solid_template(jsx, component, unique_ids),
+            Target::Vue => vue_template(jsx, component, unique_ids),
+            Target::Svelte => svelte_template(jsx, component, unique_ids),
+        }
+    }
+}
+
+fn react_template(jsx: &JsxElement, mut component: Component, unique_ids: bool) -> String {
+    if unique_ids {
+        component.statements.insert(
+            0,
+            format!(
+                "const {} = React.useId().replace(/[^a-zA-Z0-9_-]/g, \"\");",
+                ID_PREFIX_VARIABLE
+            ),
+        );
+    }
+    let mut jsx = jsx.clone();
+    remove_namespaced_attributes(&mut jsx);
+    component.content = render_markup(&jsx, 2, Markup::React);
+    component_template(&component)
+}
+
+fn qualify_props(element: &mut JsxElement, names: &[String], object: &str) {
+    let qualify = |value: &mut JsxValue| {
+        if let JsxValue::Expression(expression) = value
+            && names.contains(expression)
+        {
+            *expression = format!("{}.{}", object, expression);
+        }
+    };
+
+    for (_, value) in element.attributes.iter_mut() {
+        match value {
+            JsxValue::Style(entries) => entries.iter_mut().for_each(|(_, entry)| qualify(entry)),
+            _ => qualify(value),
+        }
+    }
+    for child in element.children.iter_mut() {
+        if let JsxNode::Element(child) = child {
+            qualify_props(child, names, object);
+        }
+    }
+}
+
+fn props_type_literal(component: &Component, separator: &str) -> String {
+    component
+        .props
+        .iter()
+        .map(|prop| format!("{}?: {};", prop.name, prop.type_name))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn solid_template(jsx: &JsxElement, component: &Component, unique_ids: bool) -> String {
+    let mut jsx = jsx.clone();
+    let mut solid = Component {
+        name: component.name.clone(),
+        filename: component.filename.clone(),
+        template: component.template.clone(),
+        props_type: SOLID_PROPS_TYPE.to_string(),
+        ..Default::default()
+    };
+    let mut imports = vec!["type JSX".to_string()];
+
+    if !component.props.is_empty() {
+        let names = component
+            .props
+            .iter()
+            .map(|prop| prop.name.clone())
+            .collect::<Vec<_>>();
+        let defaults = component
+            .props
+            .iter()
+            .filter_map(|prop| {
+                prop.default
+                    .as_ref()
+                    .map(|default| format!("{}: {}", prop.name, default))
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let keys = names
+            .iter()
+            .map(|name| format!("\"{}\"", name))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        solid.props_type = format!(
+            "{} & {{ {} }}",
+            SOLID_PROPS_TYPE,
+            props_type_literal(component, " ")
+        );
+        solid.statements.push(format!(
+            "const [local, others] = splitProps(mergeProps({{ {} }}, props), [{}]);",
+            defaults, keys
+        ));
+        imports.extend(["mergeProps".to_string(), "splitProps".to_string()]);
+        qualify_props(&mut jsx, &names, "local");
+        jsx.spreads = vec!["others".to_string()];
+    }
+
+    if unique_ids {
+        solid
+            .statements
+            .push(format!("const {} = createUniqueId();", ID_PREFIX_VARIABLE));
+        imports.push("createUniqueId".to_string());
+    }
+
+    solid.imports = vec![format!(
+        "import {{ {} }} from \"solid-js\";",
+        imports.join(", ")
+    )];
+    solid
+        .statements
+        .extend(component.statements.iter().cloned());
+    solid.content = render_markup(&jsx, 2, Markup::Solid);
+    component_template(&solid)
+}
+
+fn vue_template(jsx: &JsxElement, component: &Component, unique_ids: bool) -> String {
+    let mut script = Vec::new();
+    if unique_ids {
+        script.push("import { useId } from \"vue\";".to_string());
+    }
+
+    if !component.props.is_empty() {
+        let defaults = component
+            .props
+            .iter()
+            .filter_map(|prop| {
+                prop.default
+                    .as_ref()
+                    .map(|default| format!("  {}: {},\n", prop.name, default))
+            })
+            .collect::<String>();
+        script.push(format!(
+            "withDefaults(defineProps<{{\n  {}\n}}>(), {{\n{}}});",
+            props_type_literal(component, "\n  "),
+            defaults
+        ));
+    }
+
+    if unique_ids {
+        script.push(format!("const {} = useId();", ID_PREFIX_VARIABLE));
+    }
+    script.extend(component.statements.iter().cloned());
+
+    let template = format!(
+        "<template>\n{}\n</template>\n",
+        render_markup(jsx, 1, Markup::Vue)
+    );
+    if script.is_empty() {
+        return template;
+    }
+    format!(
+        "<script setup lang=\"ts\">\n{}\n</script>\n\n{}",
+        script.join("\n\n"),
+        template
+    )
+}
+
+fn svelte_template(jsx: &JsxElement, component: &Component, unique_ids: bool) -> String {
+    let mut script =
+        vec!["  import type { SVGAttributes } from \"svelte/elements\";\n".to_string()];
+
+    let bindings = component
+        .props
+        .iter()
+        .map(|prop| match &prop.default {
+            Some(default) => format!("{} = {}, ", prop.name, default),
+            None => format!("{}, ", prop.name),
+        })
+        .collect::<String>();
+    let props_type = if component.props.is_empty() {
+        SVELTE_PROPS_TYPE.to_string()
+    } else {
+        format!(
+            "{} & {{ {} }}",
+            SVELTE_PROPS_TYPE,
+            props_type_literal(component, " ")
+        )
+    };
+    script.push(format!(
+        "  let {{ {}...props }}: {} = $props();",
+        bindings, props_type
+    ));
+
+    if unique_ids {
+        script.push(format!("  const {} = $props.id();", ID_PREFIX_VARIABLE));
+    }
+    script.extend(
+        component
+            .statements
+            .iter()
+            .map(|statement| format!("  {}", statement)),
+    );
+
+    format!(
+        "<script lang=\"ts\">\n{}\n</script>\n\n{}\n",
+        script.join("\n"),
+        render_markup(jsx, 0, Markup::Svelte)
+    )
+}